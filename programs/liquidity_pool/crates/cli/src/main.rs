@@ -0,0 +1,349 @@
+//! Operator command-line tool for the liquidity pool program, built on `liquidity_pool_client`.
+//! Works against any RPC endpoint, e.g. `--url http://127.0.0.1:8899` for a local test validator.
+
+mod rpc;
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context as _, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use liquidity_pool_client::{instructions, pda, quote, vaa::VaaInfo};
+use solana_sdk::{pubkey::Pubkey, signature::read_keypair_file};
+
+use rpc::Context;
+
+#[derive(Parser)]
+#[command(about = "Operate liquidity pools")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Fee payer and signer keypair (defaults to the Solana CLI keypair)
+    #[arg(long, short = 'k')]
+    keypair: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a pool for two mints (admin)
+    CreatePool {
+        #[arg(long)]
+        mint_a: Pubkey,
+        #[arg(long)]
+        mint_b: Pubkey,
+        /// Swap fee in basis points
+        #[arg(long, default_value_t = 30)]
+        fee_bps: u64,
+        /// Cross-chain pool ID shared with Sui (32 bytes, hex)
+        #[arg(long)]
+        pool_id: String,
+    },
+    /// Deposit both tokens
+    AddLiquidity {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        amount_a: u64,
+        #[arg(long)]
+        amount_b: u64,
+        #[command(flatten)]
+        limits: Limits,
+    },
+    /// Burn LP tokens for both tokens
+    RemoveLiquidity {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        lp_amount: u64,
+        #[command(flatten)]
+        limits: Limits,
+    },
+    /// Swap one token for the other
+    Swap {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        amount_in: u64,
+        /// Token sold
+        #[arg(long, value_enum)]
+        from: Side,
+        #[command(flatten)]
+        limits: Limits,
+    },
+    /// Pause a pool (admin)
+    Pause {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Unpause a pool (admin)
+    Unpause {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Change the swap fee of a pool (admin)
+    SetFee {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        fee_bps: u64,
+    },
+    /// Transfer the accrued protocol fees (admin)
+    CollectFees {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Token A receiver (defaults to the admin's associated token account)
+        #[arg(long)]
+        receiver_a: Option<Pubkey>,
+        /// Token B receiver (defaults to the admin's associated token account)
+        #[arg(long)]
+        receiver_b: Option<Pubkey>,
+    },
+    /// Print the decoded pool, its reserves and price
+    ShowPool {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Submit a VAA (binary or hex file) to the matching process_*_vaa instruction
+    ProcessVaa {
+        file: PathBuf,
+        /// Account holding the VAA (defaults to the core bridge PostedVAA PDA)
+        #[arg(long)]
+        posted_vaa: Option<Pubkey>,
+    },
+}
+
+#[derive(clap::Args)]
+struct Limits {
+    /// Accepted slippage against the quote, in basis points
+    #[arg(long, default_value_t = 50)]
+    slippage_bps: u64,
+    /// Seconds until the transaction deadline
+    #[arg(long, default_value_t = 60)]
+    deadline_secs: i64,
+}
+
+impl Limits {
+    fn deadline(&self) -> Result<i64> {
+        Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64 + self.deadline_secs)
+    }
+
+    fn minimum(&self, amount: u64) -> Result<u64> {
+        quote::with_slippage(amount, self.slippage_bps).map_err(|e| anyhow!("{}", e))
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Side {
+    A,
+    B,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let keypair_path = match cli.keypair {
+        Some(path) => path,
+        None => PathBuf::from(std::env::var("HOME")?).join(".config/solana/id.json"),
+    };
+    let payer = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow!("reading keypair {}: {}", keypair_path.display(), e))?;
+    let ctx = Context::new(cli.url, payer);
+
+    match cli.command {
+        Command::CreatePool { mint_a, mint_b, fee_bps, pool_id } => create_pool(&ctx, mint_a, mint_b, fee_bps, &pool_id),
+        Command::AddLiquidity { pool, amount_a, amount_b, limits } => add_liquidity(&ctx, pool, amount_a, amount_b, &limits),
+        Command::RemoveLiquidity { pool, lp_amount, limits } => remove_liquidity(&ctx, pool, lp_amount, &limits),
+        Command::Swap { pool, amount_in, from, limits } => swap(&ctx, pool, amount_in, from, &limits),
+        Command::Pause { pool } => set_status(&ctx, pool, 1),
+        Command::Unpause { pool } => set_status(&ctx, pool, 0),
+        Command::SetFee { pool, fee_bps } => {
+            let signature = ctx.send(&[instructions::set_fee(&ctx.payer(), &pool, fee_bps)], &[])?;
+            println!("Fee of {} set to {} bps: {}", pool, fee_bps, signature);
+            Ok(())
+        }
+        Command::CollectFees { pool, receiver_a, receiver_b } => collect_fees(&ctx, pool, receiver_a, receiver_b),
+        Command::ShowPool { pool } => show_pool(&ctx, pool),
+        Command::ProcessVaa { file, posted_vaa } => process_vaa(&ctx, &file, posted_vaa),
+    }
+}
+
+fn create_pool(ctx: &Context, mint_a: Pubkey, mint_b: Pubkey, fee_bps: u64, pool_id: &str) -> Result<()> {
+    let pool_id: [u8; 32] = hex::decode(pool_id.trim_start_matches("0x"))?
+        .try_into()
+        .map_err(|_| anyhow!("pool ID must be 32 bytes"))?;
+    let signature = ctx.send(&[instructions::create_pool(&ctx.payer(), &mint_a, &mint_b, fee_bps, pool_id)], &[])?;
+    println!("Pool {} created: {}", pda::pool(&mint_a, &mint_b), signature);
+    Ok(())
+}
+
+fn add_liquidity(ctx: &Context, pool: Pubkey, amount_a: u64, amount_b: u64, limits: &Limits) -> Result<()> {
+    let (_, addresses) = ctx.pool(&pool)?;
+    let ledger = ctx.ledger(&addresses.ledger)?;
+    let quote = quote::quote_add_liquidity(&ledger, amount_a, amount_b).map_err(|e| anyhow!("{}", e))?;
+    println!("Depositing A={} B={} for {} LP", quote.amount_a, quote.amount_b, quote.liquidity);
+
+    let instruction = instructions::add_liquidity(
+        &ctx.payer(),
+        &addresses,
+        amount_a,
+        amount_b,
+        limits.minimum(quote.amount_a)?,
+        limits.minimum(quote.amount_b)?,
+        limits.deadline()?,
+    );
+    println!("Liquidity added: {}", ctx.send(&[instruction], &[])?);
+    Ok(())
+}
+
+fn remove_liquidity(ctx: &Context, pool: Pubkey, lp_amount: u64, limits: &Limits) -> Result<()> {
+    let (_, addresses) = ctx.pool(&pool)?;
+    let ledger = ctx.ledger(&addresses.ledger)?;
+    let quote = quote::quote_remove_liquidity(&ledger, lp_amount).map_err(|e| anyhow!("{}", e))?;
+    println!("Burning {} LP for A={} B={}", lp_amount, quote.amount_a, quote.amount_b);
+
+    let (_, create_a) = ctx.ata(&ctx.payer(), &addresses.token_a_mint);
+    let (_, create_b) = ctx.ata(&ctx.payer(), &addresses.token_b_mint);
+    let instruction = instructions::remove_liquidity(
+        &ctx.payer(),
+        &addresses,
+        lp_amount,
+        limits.minimum(quote.amount_a)?,
+        limits.minimum(quote.amount_b)?,
+        limits.deadline()?,
+    );
+    println!("Liquidity removed: {}", ctx.send(&[create_a, create_b, instruction], &[])?);
+    Ok(())
+}
+
+fn swap(ctx: &Context, pool: Pubkey, amount_in: u64, from: Side, limits: &Limits) -> Result<()> {
+    let (pool_state, addresses) = ctx.pool(&pool)?;
+    let (vault_a, vault_b) = ctx.vaults(&addresses)?;
+    let a_to_b = matches!(from, Side::A);
+    let quote = quote::quote_swap(&pool_state, vault_a, vault_b, amount_in, a_to_b).map_err(|e| anyhow!("{}", e))?;
+    println!("Swapping {} for {} (protocol fee {})", amount_in, quote.amount_out, quote.protocol_fee);
+
+    let mint_out = if a_to_b { addresses.token_b_mint } else { addresses.token_a_mint };
+    let (_, create_out) = ctx.ata(&ctx.payer(), &mint_out);
+    let instruction = instructions::swap(
+        &ctx.payer(),
+        &addresses,
+        amount_in,
+        limits.minimum(quote.amount_out)?,
+        a_to_b,
+        limits.deadline()?,
+    );
+    println!("Swapped: {}", ctx.send(&[create_out, instruction], &[])?);
+    Ok(())
+}
+
+fn set_status(ctx: &Context, pool: Pubkey, status: u8) -> Result<()> {
+    let signature = ctx.send(&[instructions::set_pool_status(&ctx.payer(), &pool, status)], &[])?;
+    println!("Status of {} set to {}: {}", pool, status_name(status), signature);
+    Ok(())
+}
+
+fn collect_fees(ctx: &Context, pool: Pubkey, receiver_a: Option<Pubkey>, receiver_b: Option<Pubkey>) -> Result<()> {
+    let (pool_state, addresses) = ctx.pool(&pool)?;
+    let mut setup = Vec::new();
+    let mut receiver = |explicit: Option<Pubkey>, mint: &Pubkey| match explicit {
+        Some(address) => address,
+        None => {
+            let (address, create) = ctx.ata(&ctx.payer(), mint);
+            setup.push(create);
+            address
+        }
+    };
+    let receiver_a = receiver(receiver_a, &addresses.token_a_mint);
+    let receiver_b = receiver(receiver_b, &addresses.token_b_mint);
+
+    setup.push(instructions::collect_fees(&ctx.payer(), &addresses, &receiver_a, &receiver_b));
+    let signature = ctx.send(&setup, &[])?;
+    println!("Collected A={} B={}: {}", pool_state.protocol_fee_a, pool_state.protocol_fee_b, signature);
+    Ok(())
+}
+
+fn show_pool(ctx: &Context, pool: Pubkey) -> Result<()> {
+    let (state, addresses) = ctx.pool(&pool)?;
+    let (vault_a, vault_b) = ctx.vaults(&addresses)?;
+    let (reserve_a, reserve_b) = state.lp_reserves(vault_a, vault_b);
+
+    println!("Pool              {}", pool);
+    println!("Pool ID           {}", hex::encode(state.pool_id));
+    println!("Status            {} ({})", state.status, status_name(state.status));
+    println!("Fee               {} bps", state.fee_percentage);
+    println!("Token A mint      {}", state.token_a_mint);
+    println!("Token B mint      {}", state.token_b_mint);
+    println!("LP mint           {}", state.lp_mint);
+    println!("Authority         {}", state.authority);
+    println!("Total liquidity   {}", state.total_liquidity);
+    println!("Last updated at   {}", state.last_updated_at);
+    println!("Vault A           {} ({})", vault_a, state.token_a_account);
+    println!("Vault B           {} ({})", vault_b, state.token_b_account);
+    println!("Protocol fees     A={} B={}", state.protocol_fee_a, state.protocol_fee_b);
+    println!("LP reserves       A={} B={}", reserve_a, reserve_b);
+
+    if let Ok(ledger) = ctx.ledger(&addresses.ledger) {
+        println!("Ledger (Solana)   A={} B={} LP={}", ledger.local_reserve_a, ledger.local_reserve_b, ledger.local_lp_supply);
+        println!("Ledger (Sui)      A={} B={} LP={}", ledger.remote_reserve_a, ledger.remote_reserve_b, ledger.remote_lp_supply);
+    }
+
+    match quote::spot_price(&state, vault_a, vault_b) {
+        Some(raw_price) => {
+            let decimals_a = ctx.mint_decimals(&state.token_a_mint)? as i32;
+            let decimals_b = ctx.mint_decimals(&state.token_b_mint)? as i32;
+            println!("Price             1 A = {} B", raw_price * 10f64.powi(decimals_a - decimals_b));
+        }
+        None => println!("Price             - (empty pool)"),
+    }
+    Ok(())
+}
+
+fn process_vaa(ctx: &Context, file: &PathBuf, posted_vaa: Option<Pubkey>) -> Result<()> {
+    let contents = std::fs::read(file).with_context(|| format!("reading {}", file.display()))?;
+    // Hex files are accepted as well as raw bytes
+    let bytes = match std::str::from_utf8(&contents) {
+        Ok(text) => hex::decode(text.trim().trim_start_matches("0x")).unwrap_or(contents),
+        Err(_) => contents,
+    };
+    let vaa = VaaInfo::parse(&bytes).map_err(|e| anyhow!("{}", e))?;
+    let posted_vaa = posted_vaa.unwrap_or_else(|| pda::posted_vaa(&vaa.hash));
+    println!(
+        "VAA {} from chain {} sequence {} (operation {})",
+        hex::encode(vaa.hash), vaa.emitter_chain, vaa.sequence, vaa.operation_code
+    );
+
+    // Locate the pool through the ledger of the payload's pool ID
+    let pool_id = vaa.pool_id().map_err(|e| anyhow!("{}", e))?;
+    let ledger = ctx.ledger(&pda::ledger(&pool_id))?;
+    let (_, addresses) = ctx.pool(&ledger.pool)?;
+
+    let signature_set = ctx.posted_vaa_signature_set(&posted_vaa)?;
+    let instruction = instructions::process_vaa(&ctx.payer(), &posted_vaa, &signature_set, &vaa, &addresses)
+        .map_err(|e| anyhow!("{}", e))?;
+
+    // Relayer fee accounts (the payer's ATAs) must exist before the instruction runs
+    let mut setup = Vec::new();
+    for mint in [addresses.token_a_mint, addresses.token_b_mint, addresses.lp_mint] {
+        let (address, create) = ctx.ata(&ctx.payer(), &mint);
+        if instruction.accounts.iter().any(|meta| meta.pubkey == address) {
+            setup.push(create);
+        }
+    }
+    setup.push(instruction);
+
+    println!("VAA processed: {}", ctx.send(&setup, &[])?);
+    Ok(())
+}
+
+fn status_name(status: u8) -> &'static str {
+    match status {
+        0 => "active",
+        1 => "paused",
+        2 => "deprecated",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{anyhow, Context as _, Result};
+use anchor_lang::AccountDeserialize;
+use anchor_spl::token::{Mint, TokenAccount};
+use liquidity_pool::state::{Pool, PoolLedger};
+use liquidity_pool_client::{accounts, pda::PoolAddresses};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account_idempotent};
+
+// RPC connection and fee payer shared by all subcommands
+pub struct Context {
+    pub client: RpcClient,
+    pub payer: Keypair,
+}
+
+impl Context {
+    pub fn new(url: String, payer: Keypair) -> Self {
+        Self { client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()), payer }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    // Sign with the payer (and `signers`), send and wait for confirmation
+    pub fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<Signature> {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&self.payer()), &all_signers, blockhash);
+        Ok(self.client.send_and_confirm_transaction(&transaction)?)
+    }
+
+    fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>> {
+        self.client.get_account_data(address).with_context(|| format!("fetching account {}", address))
+    }
+
+    fn decode<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        accounts::decode(&self.account_data(address)?).map_err(|e| anyhow!("decoding {}: {}", address, e))
+    }
+
+    pub fn pool(&self, address: &Pubkey) -> Result<(Pool, PoolAddresses)> {
+        let pool: Pool = self.decode(address)?;
+        let addresses = PoolAddresses::from_pool(&pool);
+        if addresses.pool != *address {
+            return Err(anyhow!("{} is not a pool of this program", address));
+        }
+        Ok((pool, addresses))
+    }
+
+    pub fn ledger(&self, address: &Pubkey) -> Result<PoolLedger> {
+        self.decode(address)
+    }
+
+    // Signature set named by a posted VAA (the VAA must have been posted to the core bridge)
+    pub fn posted_vaa_signature_set(&self, posted_vaa: &Pubkey) -> Result<Pubkey> {
+        accounts::decode_posted_vaa_signature_set(&self.account_data(posted_vaa)?)
+            .map_err(|e| anyhow!("decoding {}: {}", posted_vaa, e))
+    }
+
+    pub fn token_balance(&self, address: &Pubkey) -> Result<u64> {
+        Ok(self.decode::<TokenAccount>(address)?.amount)
+    }
+
+    pub fn mint_decimals(&self, address: &Pubkey) -> Result<u8> {
+        Ok(self.decode::<Mint>(address)?.decimals)
+    }
+
+    // Pool vault balances (token A, token B)
+    pub fn vaults(&self, pool: &PoolAddresses) -> Result<(u64, u64)> {
+        Ok((self.token_balance(&pool.token_a_account)?, self.token_balance(&pool.token_b_account)?))
+    }
+
+    // Associated token account of `owner` and the instruction creating it if missing
+    pub fn ata(&self, owner: &Pubkey, mint: &Pubkey) -> (Pubkey, Instruction) {
+        (
+            get_associated_token_address(owner, mint),
+            create_associated_token_account_idempotent(&self.payer(), owner, mint, &anchor_spl::token::ID),
+        )
+    }
+}
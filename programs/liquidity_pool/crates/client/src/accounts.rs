@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::state::{BridgeRequest, Config, Pool, PoolLedger, Position, RemotePoolState, SequenceTracker};
+
+// Decode the data of a program account (checks the Anchor discriminator)
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_pool(data: &[u8]) -> Result<Pool> {
+    decode(data)
+}
+
+pub fn decode_position(data: &[u8]) -> Result<Position> {
+    decode(data)
+}
+
+pub fn decode_bridge_request(data: &[u8]) -> Result<BridgeRequest> {
+    decode(data)
+}
+
+pub fn decode_ledger(data: &[u8]) -> Result<PoolLedger> {
+    decode(data)
+}
+
+pub fn decode_config(data: &[u8]) -> Result<Config> {
+    decode(data)
+}
+
+pub fn decode_remote_pool_state(data: &[u8]) -> Result<RemotePoolState> {
+    decode(data)
+}
+
+pub fn decode_sequence_tracker(data: &[u8]) -> Result<SequenceTracker> {
+    decode(data)
+}
+
+// Signature set named by a core bridge PostedVAA account, passed along with it to the `process_*_vaa` instructions.
+// Layout: b"vaa" magic + vaa_version (1) + consistency_level (1) + vaa_time (4) + signature_set (32) + ...
+pub fn decode_posted_vaa_signature_set(data: &[u8]) -> Result<Pubkey> {
+    require!(data.starts_with(b"vaa"), ErrorCode::InvalidPostedVaa);
+    let signature_set = data.get(9..41).ok_or(error!(ErrorCode::InvalidPostedVaa))?;
+    Ok(Pubkey::new_from_array(signature_set.try_into().unwrap()))
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    secp256k1_program, system_program, sysvar,
+};
+use liquidity_pool::errors::ErrorCode;
+
+use crate::{pda, vaa::VaaInfo, WORMHOLE_PROGRAM_ID};
+
+// Posting a VAA through the core bridge: `verify_signatures` (checked by a preceding secp256k1
+// instruction) records the guardian signatures in a signature set account, then `post_vaa`
+// writes the VAA to its PostedVAA PDA once a quorum is verified.
+
+pub const VERIFY_SIGNATURES: u8 = 7;
+pub const POST_VAA: u8 = 2;
+pub const MAX_GUARDIANS: usize = 19;
+// Signatures verified per transaction (transaction size limit)
+pub const SIGNATURES_PER_TRANSACTION: usize = 7;
+
+const SIGNATURE_SIZE: usize = 66; // guardian index (1) + signature (64) + recovery id (1)
+pub const SECP_OFFSETS_SIZE: usize = 11;
+const SECP_ENTRY_SIZE: usize = 20 + 65; // eth address + signature + recovery id
+
+// Core bridge guardian set account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GuardianSetData {
+    pub index: u32,
+    pub keys: Vec<[u8; 20]>,
+    pub creation_time: u32,
+    pub expiration_time: u32,
+}
+
+// Data of the core bridge `post_vaa` instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PostVaaData {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+// Guardian signatures of a raw VAA: (guardian index, 65-byte signature)
+fn signatures(bytes: &[u8]) -> Result<Vec<(u8, [u8; 65])>> {
+    let count = *bytes.get(5).ok_or(error!(ErrorCode::InvalidVaaPayload))? as usize;
+    (0..count)
+        .map(|i| {
+            let start = 6 + i * SIGNATURE_SIZE;
+            let entry = bytes.get(start..start + SIGNATURE_SIZE).ok_or(error!(ErrorCode::InvalidVaaPayload))?;
+            Ok((entry[0], entry[1..].try_into().unwrap()))
+        })
+        .collect()
+}
+
+// Transactions (instruction pairs) verifying the VAA's signatures into `signature_set`.
+// Each signature is checked against the key `guardian_set` holds at the signature's guardian index.
+pub fn verify_signatures_instructions(
+    payer: &Pubkey,
+    signature_set: &Pubkey,
+    vaa_bytes: &[u8],
+    vaa: &VaaInfo,
+    guardian_set: &GuardianSetData,
+) -> Result<Vec<[Instruction; 2]>> {
+    let signatures = signatures(vaa_bytes)?;
+    signatures
+        .chunks(SIGNATURES_PER_TRANSACTION)
+        .map(|batch| {
+            let mut signers = [-1i8; MAX_GUARDIANS];
+            let mut entries = Vec::with_capacity(batch.len());
+            for (position, (guardian_index, signature)) in batch.iter().enumerate() {
+                let key = guardian_set.keys.get(*guardian_index as usize).ok_or_else(|| {
+                    msg!("Guardian {} is not in set {}", guardian_index, guardian_set.index);
+                    error!(ErrorCode::InvalidVaaPayload)
+                })?;
+                signers[*guardian_index as usize] = position as i8;
+                entries.push((*key, *signature));
+            }
+
+            let secp = secp256k1_instruction(&entries, &vaa.hash);
+            let mut data = vec![VERIFY_SIGNATURES];
+            data.extend(signers.iter().map(|signer| *signer as u8));
+            let verify = Instruction {
+                program_id: WORMHOLE_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(*payer, true),
+                    AccountMeta::new_readonly(pda::guardian_set(vaa.guardian_set_index), false),
+                    AccountMeta::new(*signature_set, true),
+                    AccountMeta::new_readonly(sysvar::instructions::ID, false),
+                    AccountMeta::new_readonly(sysvar::rent::ID, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+                data,
+            };
+            Ok([secp, verify])
+        })
+        .collect()
+}
+
+// secp256k1 program instruction checking each (eth address, signature) over the VAA hash.
+// The program hashes the message again, matching the guardians signing keccak256(keccak256(body)).
+fn secp256k1_instruction(entries: &[([u8; 20], [u8; 65])], message: &[u8; 32]) -> Instruction {
+    let data_start = 1 + entries.len() * SECP_OFFSETS_SIZE;
+    let message_offset = data_start + entries.len() * SECP_ENTRY_SIZE;
+
+    let mut data = vec![entries.len() as u8];
+    for i in 0..entries.len() {
+        let entry_offset = data_start + i * SECP_ENTRY_SIZE;
+        data.extend_from_slice(&((entry_offset + 20) as u16).to_le_bytes()); // signature
+        data.push(0);
+        data.extend_from_slice(&(entry_offset as u16).to_le_bytes()); // eth address
+        data.push(0);
+        data.extend_from_slice(&(message_offset as u16).to_le_bytes());
+        data.extend_from_slice(&32u16.to_le_bytes());
+        data.push(0);
+    }
+    for (address, signature) in entries {
+        data.extend_from_slice(address);
+        data.extend_from_slice(signature);
+    }
+    data.extend_from_slice(message);
+
+    Instruction { program_id: secp256k1_program::ID, accounts: vec![], data }
+}
+
+pub fn post_vaa_instruction(payer: &Pubkey, signature_set: &Pubkey, vaa_bytes: &[u8], vaa: &VaaInfo) -> Result<Instruction> {
+    let body_offset = 6 + SIGNATURE_SIZE * vaa_bytes[5] as usize;
+    let body = &vaa_bytes[body_offset..];
+    let mut payload = vec![vaa.operation_code];
+    payload.extend_from_slice(&vaa.payload);
+
+    let mut data = vec![POST_VAA];
+    PostVaaData {
+        version: vaa_bytes[0],
+        guardian_set_index: vaa.guardian_set_index,
+        timestamp: vaa.timestamp,
+        nonce: u32::from_be_bytes(body[4..8].try_into().unwrap()),
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        consistency_level: body[50],
+        payload,
+    }
+    .serialize(&mut data)?;
+
+    Ok(Instruction {
+        program_id: WORMHOLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(pda::guardian_set(vaa.guardian_set_index), false),
+            AccountMeta::new_readonly(pda::wormhole_bridge(), false),
+            AccountMeta::new_readonly(*signature_set, false),
+            AccountMeta::new(pda::posted_vaa(&vaa.hash), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    })
+}
@@ -0,0 +1,615 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::payloads::{
+    AddLiquidityCompletionPayload, BridgeOperationCode, LpMigrationPayload, RemoveLiquidityCompletionPayload,
+    SwapIntentPayload,
+};
+use liquidity_pool::state::{OperationConsistency, TokenSide};
+use liquidity_pool::{accounts, instruction};
+use wormhole_anchor_sdk::wormhole;
+
+use crate::pda::{self, PoolAddresses};
+use crate::vaa::VaaInfo;
+
+// One builder per program instruction. User token accounts are the user's associated token
+// accounts; callers needing other accounts can patch `Instruction::accounts`.
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: liquidity_pool::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn create_pool(
+    admin: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_percentage: u64,
+    pool_id: [u8; 32],
+) -> Instruction {
+    let pool = PoolAddresses::derive(token_a_mint, token_b_mint, &pool_id);
+    build(
+        accounts::CreatePool {
+            admin: *admin,
+            config: pda::config(),
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_mint: *token_a_mint,
+            token_b_mint: *token_b_mint,
+            lp_mint: pool.lp_mint,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            locked_lp_token_account: pool.locked_lp_token_account,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::CreatePool { fee_percentage, pool_id },
+    )
+}
+
+pub fn add_liquidity(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    amount_a_desired: u64,
+    amount_b_desired: u64,
+    amount_a_min: u64,
+    amount_b_min: u64,
+    deadline: i64,
+) -> Instruction {
+    build(
+        accounts::AddLiquidity {
+            user: *user,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            user_token_a: get_associated_token_address(user, &pool.token_a_mint),
+            user_token_b: get_associated_token_address(user, &pool.token_b_mint),
+            lp_mint: pool.lp_mint,
+            locked_lp_token_account: pool.locked_lp_token_account,
+            user_lp_token_account: get_associated_token_address(user, &pool.lp_mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::AddLiquidity { amount_a_desired, amount_b_desired, amount_a_min, amount_b_min, deadline },
+    )
+}
+
+pub fn remove_liquidity(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    liquidity_amount: u64,
+    amount_a_min: u64,
+    amount_b_min: u64,
+    deadline: i64,
+) -> Instruction {
+    build(
+        accounts::RemoveLiquidity {
+            user: *user,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            user_token_a: get_associated_token_address(user, &pool.token_a_mint),
+            user_token_b: get_associated_token_address(user, &pool.token_b_mint),
+            lp_mint: pool.lp_mint,
+            user_lp_token_account: get_associated_token_address(user, &pool.lp_mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::RemoveLiquidity { liquidity_amount, amount_a_min, amount_b_min, deadline },
+    )
+}
+
+pub fn swap(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    amount_in: u64,
+    min_amount_out: u64,
+    a_to_b: bool,
+    deadline: i64,
+) -> Instruction {
+    build(
+        accounts::Swap {
+            user: *user,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            user_token_a: get_associated_token_address(user, &pool.token_a_mint),
+            user_token_b: get_associated_token_address(user, &pool.token_b_mint),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::Swap { amount_in, min_amount_out, a_to_b, deadline },
+    )
+}
+
+pub fn add_liquidity_single_sided(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    amount_in: u64,
+    min_lp_out: u64,
+    side: TokenSide,
+    deadline: i64,
+) -> Instruction {
+    build(
+        accounts::AddLiquiditySingleSided {
+            user: *user,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            user_token_a: get_associated_token_address(user, &pool.token_a_mint),
+            user_token_b: get_associated_token_address(user, &pool.token_b_mint),
+            lp_mint: pool.lp_mint,
+            user_lp_token_account: get_associated_token_address(user, &pool.lp_mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::AddLiquiditySingleSided { amount_in, min_lp_out, side, deadline },
+    )
+}
+
+pub fn remove_liquidity_single_sided(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    lp_amount: u64,
+    min_out: u64,
+    side: TokenSide,
+    deadline: i64,
+) -> Instruction {
+    let mint_out = match side {
+        TokenSide::A => pool.token_a_mint,
+        TokenSide::B => pool.token_b_mint,
+    };
+    build(
+        accounts::RemoveLiquiditySingleSided {
+            user: *user,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            user_token_out: get_associated_token_address(user, &mint_out),
+            lp_mint: pool.lp_mint,
+            user_lp_token_account: get_associated_token_address(user, &pool.lp_mint),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::RemoveLiquiditySingleSided { lp_amount, min_out, side, deadline },
+    )
+}
+
+// `wormhole_message` is a new keypair that must also sign the transaction
+pub fn bridge_lp_out(
+    user: &Pubkey,
+    pool: &PoolAddresses,
+    wormhole_message: &Pubkey,
+    lp_amount: u64,
+    sui_recipient: [u8; 32],
+    deadline: i64,
+) -> Instruction {
+    let emitter = pda::wormhole_emitter();
+    build(
+        accounts::BridgeLpOut {
+            user: *user,
+            pool: pool.pool,
+            ledger: pool.ledger,
+            lp_mint: pool.lp_mint,
+            user_lp_token_account: get_associated_token_address(user, &pool.lp_mint),
+            config: pda::config(),
+            wormhole_program: wormhole::program::ID,
+            wormhole_bridge: pda::wormhole_bridge(),
+            wormhole_message: *wormhole_message,
+            wormhole_emitter: emitter,
+            wormhole_sequence: pda::wormhole_sequence(&emitter),
+            wormhole_fee_collector: pda::wormhole_fee_collector(),
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::BridgeLpOut { lp_amount, sui_recipient, deadline },
+    )
+}
+
+// Accounts shared by every `process_*_vaa` instruction
+struct VaaAccounts {
+    bridge_request: Pubkey,
+    config: Pubkey,
+    sequence_tracker: Pubkey,
+}
+
+impl VaaAccounts {
+    fn new(vaa: &VaaInfo) -> Self {
+        Self {
+            bridge_request: pda::bridge_request(&vaa.hash),
+            config: pda::config(),
+            sequence_tracker: pda::sequence_tracker(vaa.emitter_chain, &vaa.emitter_address),
+        }
+    }
+}
+
+// Token account a bridge completion delivers to: the payload's target or the recipient's ATA
+fn delivery_account(target: Option<[u8; 32]>, recipient: &Pubkey, mint: &Pubkey) -> Pubkey {
+    target.map(Pubkey::new_from_array).unwrap_or_else(|| get_associated_token_address(recipient, mint))
+}
+
+// Relayer fee account of the payer, only passed when the payload pays a fee
+fn relayer_account(relayer_fee: u64, payer: &Pubkey, mint: &Pubkey) -> Option<Pubkey> {
+    (relayer_fee > 0).then(|| get_associated_token_address(payer, mint))
+}
+
+// Build the `process_*_vaa` instruction matching the VAA's operation code, with the accounts
+// required by its payload. `signature_set` is the signature set recorded in the PostedVAA
+// (`accounts::decode_posted_vaa_signature_set`). When the payload names a receiver program,
+// the accounts it expects must be appended by the caller (they are forwarded as remaining accounts).
+pub fn process_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+) -> Result<Instruction> {
+    match vaa.operation_code {
+        code if code == BridgeOperationCode::AddLiquidityCompletion as u8 => {
+            let payload = AddLiquidityCompletionPayload::try_from_slice(&vaa.payload)
+                .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+            Ok(process_add_liquidity_vaa(payer, posted_vaa, signature_set, vaa, pool, &payload))
+        }
+        code if code == BridgeOperationCode::RemoveLiquidityCompletion as u8 => {
+            let payload = RemoveLiquidityCompletionPayload::try_from_slice(&vaa.payload)
+                .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+            Ok(process_remove_liquidity_vaa(payer, posted_vaa, signature_set, vaa, pool, &payload))
+        }
+        code if code == BridgeOperationCode::SwapIntent as u8 => {
+            let payload = SwapIntentPayload::try_from_slice(&vaa.payload)
+                .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+            Ok(process_swap_vaa(payer, posted_vaa, signature_set, vaa, pool, &payload))
+        }
+        code if code == BridgeOperationCode::ReserveSnapshot as u8 => {
+            Ok(process_reserve_snapshot_vaa(payer, posted_vaa, signature_set, vaa, pool))
+        }
+        code if code == BridgeOperationCode::LpMigration as u8 => {
+            let payload = LpMigrationPayload::try_from_slice(&vaa.payload)
+                .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+            Ok(process_lp_migration_vaa(payer, posted_vaa, signature_set, vaa, pool, &payload))
+        }
+        _ => err!(ErrorCode::InvalidBridgeOperation),
+    }
+}
+
+pub fn process_add_liquidity_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+    payload: &AddLiquidityCompletionPayload,
+) -> Instruction {
+    let shared = VaaAccounts::new(vaa);
+    let recipient = Pubkey::new_from_array(payload.recipient_address);
+    build(
+        accounts::ProcessAddLiquidityVAA {
+            payer: *payer,
+            posted_vaa: *posted_vaa,
+            signature_set: *signature_set,
+            bridge_request: shared.bridge_request,
+            config: shared.config,
+            sequence_tracker: shared.sequence_tracker,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            lp_mint: pool.lp_mint,
+            recipient,
+            recipient_lp_token_account: delivery_account(payload.target_token_account, &recipient, &pool.lp_mint),
+            relayer_lp_token_account: relayer_account(payload.relayer_fee, payer, &pool.lp_mint),
+            receiver_program: payload.receiver_program.map(Pubkey::new_from_array),
+            delivery_authority: pda::delivery_authority(&pool.pool),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::ProcessAddLiquidityVaa { vaa_hash: vaa.hash },
+    )
+}
+
+pub fn process_remove_liquidity_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+    payload: &RemoveLiquidityCompletionPayload,
+) -> Instruction {
+    let shared = VaaAccounts::new(vaa);
+    let recipient = Pubkey::new_from_array(payload.recipient_address);
+    build(
+        accounts::ProcessRemoveLiquidityVAA {
+            payer: *payer,
+            posted_vaa: *posted_vaa,
+            signature_set: *signature_set,
+            bridge_request: shared.bridge_request,
+            config: shared.config,
+            sequence_tracker: shared.sequence_tracker,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            recipient,
+            recipient_token_a_account: delivery_account(payload.target_token_a_account, &recipient, &pool.token_a_mint),
+            recipient_token_b_account: delivery_account(payload.target_token_b_account, &recipient, &pool.token_b_mint),
+            relayer_token_a_account: relayer_account(payload.relayer_fee_a, payer, &pool.token_a_mint),
+            relayer_token_b_account: relayer_account(payload.relayer_fee_b, payer, &pool.token_b_mint),
+            receiver_program: payload.receiver_program.map(Pubkey::new_from_array),
+            delivery_authority: pda::delivery_authority(&pool.pool),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::ProcessRemoveLiquidityVaa { vaa_hash: vaa.hash },
+    )
+}
+
+pub fn process_swap_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+    payload: &SwapIntentPayload,
+) -> Instruction {
+    let shared = VaaAccounts::new(vaa);
+    let recipient = Pubkey::new_from_array(payload.recipient_address);
+    let mint_out = if payload.a_to_b { pool.token_b_mint } else { pool.token_a_mint };
+    let emitter = pda::wormhole_emitter();
+    build(
+        accounts::ProcessSwapVAA {
+            payer: *payer,
+            wormhole_program: wormhole::program::ID,
+            wormhole_bridge: pda::wormhole_bridge(),
+            posted_vaa: *posted_vaa,
+            signature_set: *signature_set,
+            bridge_request: shared.bridge_request,
+            config: shared.config,
+            sequence_tracker: shared.sequence_tracker,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            recipient,
+            recipient_token_account: delivery_account(payload.target_token_account, &recipient, &mint_out),
+            relayer_token_account: relayer_account(payload.relayer_fee, payer, &mint_out),
+            wormhole_message: pda::refund_message(&vaa.hash),
+            wormhole_emitter: emitter,
+            wormhole_sequence: pda::wormhole_sequence(&emitter),
+            wormhole_fee_collector: pda::wormhole_fee_collector(),
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::ProcessSwapVaa { vaa_hash: vaa.hash },
+    )
+}
+
+pub fn process_reserve_snapshot_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+) -> Instruction {
+    let shared = VaaAccounts::new(vaa);
+    build(
+        accounts::ProcessReserveSnapshotVAA {
+            payer: *payer,
+            posted_vaa: *posted_vaa,
+            signature_set: *signature_set,
+            config: shared.config,
+            sequence_tracker: shared.sequence_tracker,
+            pool: pool.pool,
+            remote_pool_state: pda::remote_pool_state(&pool.pool_id),
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::ProcessReserveSnapshotVaa { vaa_hash: vaa.hash },
+    )
+}
+
+pub fn process_lp_migration_vaa(
+    payer: &Pubkey,
+    posted_vaa: &Pubkey,
+    signature_set: &Pubkey,
+    vaa: &VaaInfo,
+    pool: &PoolAddresses,
+    payload: &LpMigrationPayload,
+) -> Instruction {
+    let shared = VaaAccounts::new(vaa);
+    let recipient = Pubkey::new_from_array(payload.recipient_address);
+    build(
+        accounts::ProcessLpMigrationVAA {
+            payer: *payer,
+            posted_vaa: *posted_vaa,
+            signature_set: *signature_set,
+            bridge_request: shared.bridge_request,
+            config: shared.config,
+            sequence_tracker: shared.sequence_tracker,
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            ledger: pool.ledger,
+            lp_mint: pool.lp_mint,
+            recipient,
+            recipient_lp_token_account: get_associated_token_address(&recipient, &pool.lp_mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::ProcessLpMigrationVaa { vaa_hash: vaa.hash },
+    )
+}
+
+// Must be signed by the program's upgrade authority, which becomes the admin
+pub fn initialize_config(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            admin: *admin,
+            config: pda::config(),
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {},
+    )
+}
+
+pub fn update_wormhole_settings(
+    admin: &Pubkey,
+    batch_nonce: Option<u32>,
+    consistency_levels: Vec<OperationConsistency>,
+) -> Instruction {
+    build(
+        accounts::UpdateWormholeSettings {
+            admin: *admin,
+            config: pda::config(),
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::UpdateWormholeSettings { batch_nonce, consistency_levels },
+    )
+}
+
+pub fn update_relayer_settings(admin: &Pubkey, rent_reimbursement_lamports: u64) -> Instruction {
+    build(
+        accounts::UpdateRelayerSettings {
+            admin: *admin,
+            config: pda::config(),
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::UpdateRelayerSettings { rent_reimbursement_lamports },
+    )
+}
+
+pub fn set_emitter_ordering(
+    admin: &Pubkey,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    ordered: bool,
+    next_expected_sequence: u64,
+) -> Instruction {
+    build(
+        accounts::SetEmitterOrdering {
+            admin: *admin,
+            config: pda::config(),
+            sequence_tracker: pda::sequence_tracker(emitter_chain, &emitter_address),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::SetEmitterOrdering { emitter_chain, emitter_address, ordered, next_expected_sequence },
+    )
+}
+
+pub fn update_drift_threshold(admin: &Pubkey, max_price_drift_bps: u64) -> Instruction {
+    build(
+        accounts::UpdateDriftThreshold {
+            admin: *admin,
+            config: pda::config(),
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::UpdateDriftThreshold { max_price_drift_bps },
+    )
+}
+
+pub fn set_pool_status(admin: &Pubkey, pool: &Pubkey, status: u8) -> Instruction {
+    build(
+        accounts::SetPoolStatus {
+            admin: *admin,
+            config: pda::config(),
+            pool: *pool,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::SetPoolStatus { status },
+    )
+}
+
+pub fn set_fee(admin: &Pubkey, pool: &Pubkey, fee_percentage: u64) -> Instruction {
+    build(
+        accounts::SetFee {
+            admin: *admin,
+            config: pda::config(),
+            pool: *pool,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::SetFee { fee_percentage },
+    )
+}
+
+pub fn collect_fees(admin: &Pubkey, pool: &PoolAddresses, fee_receiver_a: &Pubkey, fee_receiver_b: &Pubkey) -> Instruction {
+    build(
+        accounts::CollectFees {
+            admin: *admin,
+            config: pda::config(),
+            pool: pool.pool,
+            pool_authority: pool.authority,
+            token_a_account: pool.token_a_account,
+            token_b_account: pool.token_b_account,
+            fee_receiver_a: *fee_receiver_a,
+            fee_receiver_b: *fee_receiver_b,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: liquidity_pool::ID,
+        },
+        instruction::CollectFees {},
+    )
+}
@@ -0,0 +1,20 @@
+//! Client for the liquidity pool program.
+//!
+//! - [`pda`]: addresses of the program (and Wormhole) PDAs
+//! - [`instructions`]: an [`Instruction`](anchor_lang::solana_program::instruction::Instruction) builder per program instruction
+//! - [`accounts`]: decoding of the program accounts
+//! - [`quote`]: off-chain quotes, computed with the same math as the handlers
+//! - [`vaa`]: parsing of the VAAs consumed by the `process_*_vaa` instructions
+//! - [`core_bridge`]: instructions posting a signed VAA to the Wormhole core bridge
+//!
+//! Everything is RPC-agnostic: callers fetch account data and send transactions with the client of their choice.
+
+pub mod accounts;
+pub mod core_bridge;
+pub mod instructions;
+pub mod pda;
+pub mod quote;
+pub mod vaa;
+
+pub use liquidity_pool::ID as PROGRAM_ID;
+pub use wormhole_anchor_sdk::wormhole::program::ID as WORMHOLE_PROGRAM_ID;
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use liquidity_pool::outbound::EMITTER_SEED;
+use liquidity_pool::state::Pool;
+use wormhole_anchor_sdk::wormhole;
+
+// Seeds match the `seeds = [...]` constraints of the Accounts structs in the program
+
+fn program_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &liquidity_pool::ID).0
+}
+
+fn wormhole_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &wormhole::program::ID).0
+}
+
+pub fn pool(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Pubkey {
+    program_address(&[b"pool", token_a_mint.as_ref(), token_b_mint.as_ref()])
+}
+
+pub fn pool_authority(pool: &Pubkey) -> Pubkey {
+    program_address(&[b"authority", pool.as_ref()])
+}
+
+// Signer of receiver program callbacks, distinct from the pool authority
+pub fn delivery_authority(pool: &Pubkey) -> Pubkey {
+    program_address(&[liquidity_pool::receiver::DELIVERY_AUTHORITY_SEED, pool.as_ref()])
+}
+
+pub fn lp_mint(pool: &Pubkey) -> Pubkey {
+    program_address(&[b"lp_mint", pool.as_ref()])
+}
+
+pub fn token_a_account(pool: &Pubkey) -> Pubkey {
+    program_address(&[b"token_a", pool.as_ref()])
+}
+
+pub fn token_b_account(pool: &Pubkey) -> Pubkey {
+    program_address(&[b"token_b", pool.as_ref()])
+}
+
+// Dead LP account holding the MINIMUM_LIQUIDITY locked by the first deposit
+pub fn locked_lp_token_account(pool: &Pubkey) -> Pubkey {
+    program_address(&[b"locked_lp", pool.as_ref()])
+}
+
+pub fn ledger(pool_id: &[u8; 32]) -> Pubkey {
+    program_address(&[b"ledger", pool_id.as_ref()])
+}
+
+pub fn remote_pool_state(pool_id: &[u8; 32]) -> Pubkey {
+    program_address(&[b"remote_pool", pool_id.as_ref()])
+}
+
+pub fn config() -> Pubkey {
+    program_address(&[b"config"])
+}
+
+// ProgramData account of the deployed program (holds the upgrade authority)
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[liquidity_pool::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+pub fn bridge_request(vaa_hash: &[u8; 32]) -> Pubkey {
+    program_address(&[b"bridge_request", vaa_hash.as_ref()])
+}
+
+pub fn sequence_tracker(emitter_chain: u16, emitter_address: &[u8; 32]) -> Pubkey {
+    program_address(&[b"sequence_tracker", emitter_chain.to_le_bytes().as_ref(), emitter_address.as_ref()])
+}
+
+// Message account of the refund posted when a swap intent can't be executed
+pub fn refund_message(vaa_hash: &[u8; 32]) -> Pubkey {
+    program_address(&[b"refund", vaa_hash.as_ref()])
+}
+
+// Signer of the `emit_cpi!` self-CPIs (#[event_cpi])
+pub fn event_authority() -> Pubkey {
+    program_address(&[b"__event_authority"])
+}
+
+// This program's Wormhole emitter
+pub fn wormhole_emitter() -> Pubkey {
+    program_address(&[EMITTER_SEED])
+}
+
+pub fn wormhole_bridge() -> Pubkey {
+    wormhole_address(&[b"Bridge"])
+}
+
+pub fn wormhole_fee_collector() -> Pubkey {
+    wormhole_address(&[b"fee_collector"])
+}
+
+pub fn wormhole_sequence(emitter: &Pubkey) -> Pubkey {
+    wormhole_address(&[b"Sequence", emitter.as_ref()])
+}
+
+pub fn guardian_set(index: u32) -> Pubkey {
+    wormhole_address(&[b"GuardianSet", index.to_be_bytes().as_ref()])
+}
+
+// Posted VAA account of the core bridge, keyed by the VAA body hash
+pub fn posted_vaa(vaa_hash: &[u8; 32]) -> Pubkey {
+    wormhole_address(&[b"PostedVAA", vaa_hash.as_ref()])
+}
+
+// All addresses of a pool, derived from its mints and cross-chain pool ID
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolAddresses {
+    pub pool: Pubkey,
+    pub pool_id: [u8; 32],
+    pub authority: Pubkey,
+    pub ledger: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_account: Pubkey,
+    pub token_b_account: Pubkey,
+    pub lp_mint: Pubkey,
+    pub locked_lp_token_account: Pubkey,
+}
+
+impl PoolAddresses {
+    pub fn derive(token_a_mint: &Pubkey, token_b_mint: &Pubkey, pool_id: &[u8; 32]) -> Self {
+        let pool = pool(token_a_mint, token_b_mint);
+        Self {
+            pool,
+            pool_id: *pool_id,
+            authority: pool_authority(&pool),
+            ledger: ledger(pool_id),
+            token_a_mint: *token_a_mint,
+            token_b_mint: *token_b_mint,
+            token_a_account: token_a_account(&pool),
+            token_b_account: token_b_account(&pool),
+            lp_mint: lp_mint(&pool),
+            locked_lp_token_account: locked_lp_token_account(&pool),
+        }
+    }
+
+    pub fn from_pool(pool: &Pool) -> Self {
+        Self::derive(&pool.token_a_mint, &pool.token_b_mint, &pool.pool_id)
+    }
+}
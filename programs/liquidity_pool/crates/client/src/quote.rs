@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::math::{self, AddLiquidityQuote};
+pub use liquidity_pool::math::{SingleSidedDepositQuote, SingleSidedWithdrawalQuote, SwapQuote};
+use liquidity_pool::state::{Pool, PoolLedger, TokenSide};
+
+// Quotes mirror the handlers step by step using `liquidity_pool::math`, so a quote is exactly what
+// the instruction executes against the same account state. `vault_a`/`vault_b` are the balances of
+// the pool's token accounts; every swap leg, including those of the single-sided instructions, is
+// priced on them (minus protocol fees), deposits and withdrawals on the ledger's combined Solana + Sui
+// totals. The single-sided quotes call the same composite `math` functions as their handlers.
+
+// Result of quoting a two-sided withdrawal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityQuote {
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
+
+// `swap` and the execution of a cross-chain swap intent
+pub fn quote_swap(pool: &Pool, vault_a: u64, vault_b: u64, amount_in: u64, a_to_b: bool) -> Result<SwapQuote> {
+    liquidity_pool::instructions::quote_swap_leg(pool, vault_a, vault_b, amount_in, a_to_b)
+}
+
+// `add_liquidity`
+pub fn quote_add_liquidity(ledger: &PoolLedger, amount_a_desired: u64, amount_b_desired: u64) -> Result<AddLiquidityQuote> {
+    math::quote_add_liquidity(
+        amount_a_desired,
+        amount_b_desired,
+        ledger.total_reserve_a()?,
+        ledger.total_reserve_b()?,
+        ledger.total_lp_supply()?,
+    )
+}
+
+// `remove_liquidity`
+pub fn quote_remove_liquidity(ledger: &PoolLedger, lp_amount: u64) -> Result<RemoveLiquidityQuote> {
+    let lp_supply = ledger.total_lp_supply()?;
+    require!(lp_supply > 0, ErrorCode::PoolEmpty);
+    Ok(RemoveLiquidityQuote {
+        amount_a: math::share_of_reserve(lp_amount, ledger.total_reserve_a()?, lp_supply)?,
+        amount_b: math::share_of_reserve(lp_amount, ledger.total_reserve_b()?, lp_supply)?,
+    })
+}
+
+// `add_liquidity_single_sided`
+pub fn quote_add_liquidity_single_sided(
+    pool: &Pool,
+    ledger: &PoolLedger,
+    vault_a: u64,
+    vault_b: u64,
+    amount_in: u64,
+    side: TokenSide,
+) -> Result<SingleSidedDepositQuote> {
+    math::quote_add_liquidity_single_sided(
+        amount_in,
+        side == TokenSide::A,
+        (ledger.total_reserve_a()?, ledger.total_reserve_b()?),
+        ledger.total_lp_supply()?,
+        pool.lp_reserves(vault_a, vault_b),
+        pool.fee_percentage,
+    )
+}
+
+// `remove_liquidity_single_sided`
+pub fn quote_remove_liquidity_single_sided(
+    pool: &Pool,
+    ledger: &PoolLedger,
+    vault_a: u64,
+    vault_b: u64,
+    lp_amount: u64,
+    side: TokenSide,
+) -> Result<SingleSidedWithdrawalQuote> {
+    math::quote_remove_liquidity_single_sided(
+        lp_amount,
+        side == TokenSide::A,
+        (ledger.total_reserve_a()?, ledger.total_reserve_b()?),
+        ledger.total_lp_supply()?,
+        pool.lp_reserves(vault_a, vault_b),
+        pool.fee_percentage,
+    )
+}
+
+// Marginal price of token A in token B at the local reserves (None for an empty pool).
+// Raw token units: scale by 10^(decimals_a - decimals_b) for a display price.
+pub fn spot_price(pool: &Pool, vault_a: u64, vault_b: u64) -> Option<f64> {
+    let (reserve_a, reserve_b) = pool.lp_reserves(vault_a, vault_b);
+    if reserve_a == 0 {
+        return None;
+    }
+    Some(reserve_b as f64 / reserve_a as f64)
+}
+
+// Minimum output accepted for `amount` with a slippage tolerance in basis points
+pub fn with_slippage(amount: u64, slippage_bps: u64) -> Result<u64> {
+    let tolerance = math::checked_sub(math::FEE_DENOMINATOR, slippage_bps)?;
+    math::mul_div(amount, tolerance, math::FEE_DENOMINATOR, math::Rounding::Down)
+}
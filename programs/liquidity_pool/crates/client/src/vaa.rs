@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::payloads::{
+    AddLiquidityCompletionPayload, BridgeOperationCode, LpMigrationPayload, RemoveLiquidityCompletionPayload,
+    ReserveSnapshotPayload, SwapIntentPayload,
+};
+
+// Signed VAA layout, as fetched from the guardians (the program reads the core bridge's PostedVAA instead):
+// header: version (1) + guardian set index (4) + signature count (1) + signatures (66 each)
+// body:   timestamp (4) + nonce (4) + emitter chain (2) + emitter address (32) + sequence (8)
+//         + consistency level (1) + payload
+const HEADER_SIZE: usize = 6;
+const SIGNATURE_SIZE: usize = 66;
+const BODY_PAYLOAD_OFFSET: usize = 4 + 4 + 2 + 32 + 8 + 1;
+
+// Fields of a VAA needed to build its `process_*_vaa` instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaaInfo {
+    pub hash: [u8; 32],           // keccak256 of the body, as computed by the program
+    pub guardian_set_index: u32,
+    pub timestamp: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub operation_code: u8,       // First payload byte (BridgeOperationCode)
+    pub payload: Vec<u8>,         // Operation payload (after the operation code)
+}
+
+impl VaaInfo {
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let signature_count = *bytes.get(5).ok_or(error!(ErrorCode::InvalidVaaPayload))? as usize;
+        let body_offset = HEADER_SIZE + SIGNATURE_SIZE * signature_count;
+        require!(bytes.len() > body_offset + BODY_PAYLOAD_OFFSET, ErrorCode::InvalidVaaPayload);
+
+        let body = &bytes[body_offset..];
+        let payload = &body[BODY_PAYLOAD_OFFSET..];
+        Ok(Self {
+            hash: keccak::hash(body).to_bytes(),
+            guardian_set_index: u32::from_be_bytes(bytes[1..5].try_into().unwrap()),
+            timestamp: u32::from_be_bytes(body[0..4].try_into().unwrap()),
+            emitter_chain: u16::from_be_bytes(body[8..10].try_into().unwrap()),
+            emitter_address: body[10..42].try_into().unwrap(),
+            sequence: u64::from_be_bytes(body[42..50].try_into().unwrap()),
+            operation_code: payload[0],
+            payload: payload[1..].to_vec(),
+        })
+    }
+
+    // Cross-chain pool ID named by the payload (locates the pool: ledger PDA -> `PoolLedger::pool`)
+    pub fn pool_id(&self) -> Result<[u8; 32]> {
+        let payload = &self.payload[..];
+        let pool_id = match self.operation_code {
+            code if code == BridgeOperationCode::AddLiquidityCompletion as u8 => {
+                AddLiquidityCompletionPayload::try_from_slice(payload).map(|p| p.original_pool_id)
+            }
+            code if code == BridgeOperationCode::RemoveLiquidityCompletion as u8 => {
+                RemoveLiquidityCompletionPayload::try_from_slice(payload).map(|p| p.original_pool_id)
+            }
+            code if code == BridgeOperationCode::SwapIntent as u8 => {
+                SwapIntentPayload::try_from_slice(payload).map(|p| p.original_pool_id)
+            }
+            code if code == BridgeOperationCode::ReserveSnapshot as u8 => {
+                ReserveSnapshotPayload::try_from_slice(payload).map(|p| p.original_pool_id)
+            }
+            code if code == BridgeOperationCode::LpMigration as u8 => {
+                LpMigrationPayload::try_from_slice(payload).map(|p| p.original_pool_id)
+            }
+            _ => return err!(ErrorCode::InvalidBridgeOperation),
+        };
+        pool_id.map_err(|_| error!(ErrorCode::InvalidVaaPayload))
+    }
+
+    // Receiver program named by a bridge completion; its accounts go in the remaining accounts
+    pub fn receiver_program(&self) -> Result<Option<Pubkey>> {
+        let payload = &self.payload[..];
+        let receiver_program = match self.operation_code {
+            code if code == BridgeOperationCode::AddLiquidityCompletion as u8 => {
+                AddLiquidityCompletionPayload::try_from_slice(payload).map(|p| p.receiver_program)
+            }
+            code if code == BridgeOperationCode::RemoveLiquidityCompletion as u8 => {
+                RemoveLiquidityCompletionPayload::try_from_slice(payload).map(|p| p.receiver_program)
+            }
+            _ => return Ok(None),
+        };
+        receiver_program
+            .map(|program| program.map(Pubkey::new_from_array))
+            .map_err(|_| error!(ErrorCode::InvalidVaaPayload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(signature_count: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&7u32.to_be_bytes());
+        bytes.push(signature_count);
+        bytes.extend(std::iter::repeat(0xAA).take(SIGNATURE_SIZE * signature_count as usize));
+        bytes.extend_from_slice(&1_700_000_000u32.to_be_bytes());
+        bytes.extend_from_slice(&0u32.to_be_bytes());
+        bytes.extend_from_slice(&21u16.to_be_bytes());
+        bytes.extend_from_slice(&[3; 32]);
+        bytes.extend_from_slice(&42u64.to_be_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn parses_header_and_body() {
+        let bytes = encode(2, &[4, 9, 9]);
+        let vaa = VaaInfo::parse(&bytes).unwrap();
+        assert_eq!(vaa.guardian_set_index, 7);
+        assert_eq!(vaa.emitter_chain, 21);
+        assert_eq!(vaa.emitter_address, [3; 32]);
+        assert_eq!(vaa.sequence, 42);
+        assert_eq!(vaa.operation_code, 4);
+        assert_eq!(vaa.payload, vec![9, 9]);
+        assert_eq!(vaa.hash, keccak::hash(&bytes[HEADER_SIZE + 2 * SIGNATURE_SIZE..]).to_bytes());
+    }
+
+    #[test]
+    fn rejects_vaa_without_payload() {
+        let bytes = encode(1, &[]);
+        assert!(VaaInfo::parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn reads_the_receiver_program_of_a_completion() {
+        let receiver = Pubkey::new_unique();
+        let mut payload = vec![BridgeOperationCode::AddLiquidityCompletion as u8];
+        AddLiquidityCompletionPayload {
+            recipient_address: [1; 32],
+            lp_amount_to_mint: 1_000,
+            original_pool_id: [2; 32],
+            relayer_fee: 0,
+            target_token_account: None,
+            receiver_program: Some(receiver.to_bytes()),
+            amount_a_deposited: 500,
+            amount_b_deposited: 500,
+        }
+        .serialize(&mut payload)
+        .unwrap();
+
+        let vaa = VaaInfo::parse(&encode(1, &payload)).unwrap();
+        assert_eq!(vaa.receiver_program().unwrap(), Some(receiver));
+        assert_eq!(VaaInfo::parse(&encode(1, &[3, 0])).unwrap().receiver_program().unwrap(), None);
+    }
+}
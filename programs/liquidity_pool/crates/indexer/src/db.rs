@@ -0,0 +1,404 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use liquidity_pool::state::BridgeStatus;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::decode::PoolEvent;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS pools (
+    pool            TEXT PRIMARY KEY,
+    pool_id         TEXT NOT NULL,
+    creator         TEXT NOT NULL,
+    token_a_mint    TEXT NOT NULL,
+    token_b_mint    TEXT NOT NULL,
+    lp_mint         TEXT NOT NULL,
+    fee_percentage  INTEGER NOT NULL,
+    status          INTEGER NOT NULL DEFAULT 0,
+    created_slot    INTEGER NOT NULL,
+    created_at      INTEGER,
+    status_slot     INTEGER NOT NULL,
+    fee_slot        INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS deposits (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    user            TEXT NOT NULL,
+    amount_a_desired INTEGER NOT NULL,
+    amount_b_desired INTEGER NOT NULL,
+    amount_a        INTEGER NOT NULL,
+    amount_b        INTEGER NOT NULL,
+    lp_tokens_minted INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS withdrawals (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    user            TEXT NOT NULL,
+    amount_a        INTEGER NOT NULL,
+    amount_b        INTEGER NOT NULL,
+    lp_tokens_burned INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS swaps (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    user            TEXT NOT NULL,
+    a_to_b          INTEGER NOT NULL,
+    amount_in       INTEGER NOT NULL,
+    amount_out      INTEGER NOT NULL,
+    protocol_fee    INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS fee_collections (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    admin           TEXT NOT NULL,
+    amount_a        INTEGER NOT NULL,
+    amount_b        INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS bridge_requests (
+    vaa_hash        TEXT PRIMARY KEY,
+    signature       TEXT NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    emitter_chain   INTEGER NOT NULL,
+    emitter_address TEXT NOT NULL,
+    sequence        INTEGER NOT NULL,
+    operation_code  INTEGER NOT NULL,
+    status          TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS lp_migrations (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    pool            TEXT NOT NULL,
+    user            TEXT NOT NULL,
+    sui_recipient   TEXT NOT NULL,
+    lp_amount       INTEGER NOT NULL,
+    reserve_a_share INTEGER NOT NULL,
+    reserve_b_share INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS emitters (
+    emitter_chain   INTEGER NOT NULL,
+    emitter_address TEXT NOT NULL,
+    ordered         INTEGER NOT NULL,
+    next_expected_sequence INTEGER NOT NULL,
+    updated_slot    INTEGER NOT NULL,
+    PRIMARY KEY (emitter_chain, emitter_address)
+);
+CREATE TABLE IF NOT EXISTS config_updates (
+    signature       TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    admin           TEXT NOT NULL,
+    setting         TEXT NOT NULL,
+    value           TEXT NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS instructions (
+    signature       TEXT NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    name            TEXT NOT NULL,
+    PRIMARY KEY (signature, instruction_index)
+);
+CREATE INDEX IF NOT EXISTS deposits_pool ON deposits (pool, slot);
+CREATE INDEX IF NOT EXISTS withdrawals_pool ON withdrawals (pool, slot);
+CREATE INDEX IF NOT EXISTS swaps_pool ON swaps (pool, slot);
+CREATE INDEX IF NOT EXISTS lp_migrations_pool ON lp_migrations (pool, slot);
+CREATE INDEX IF NOT EXISTS fee_collections_pool ON fee_collections (pool, slot);
+CREATE VIEW IF NOT EXISTS pool_fees AS
+    SELECT pool, SUM(amount_a) AS fees_collected_a, SUM(amount_b) AS fees_collected_b
+    FROM fee_collections GROUP BY pool;
+CREATE TABLE IF NOT EXISTS indexer_state (
+    key             TEXT PRIMARY KEY,
+    value           TEXT NOT NULL
+);
+";
+
+// Where a decoded event or instruction was found
+pub struct Location<'a> {
+    pub signature: &'a str,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub index: u32,
+}
+
+pub struct Database {
+    conn: Connection,
+}
+
+impl Database {
+    pub fn open(path: &str) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    // Run `f` in a single SQLite transaction (one per indexed Solana transaction)
+    pub fn transaction<T>(&mut self, f: impl FnOnce(&rusqlite::Transaction) -> Result<T>) -> Result<T> {
+        let tx = self.conn.transaction()?;
+        let value = f(&tx)?;
+        tx.commit()?;
+        Ok(value)
+    }
+
+    pub fn cursor(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row("SELECT value FROM indexer_state WHERE key = 'last_signature'", [], |row| row.get(0))
+            .optional()?)
+    }
+}
+
+// Advance the RPC cursor in the same SQLite transaction as the events of `signature`
+pub fn set_cursor(tx: &rusqlite::Transaction, signature: &str) -> Result<()> {
+    tx.execute(
+        "INSERT INTO indexer_state (key, value) VALUES ('last_signature', ?1)
+         ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+        params![signature],
+    )?;
+    Ok(())
+}
+
+pub fn insert_instruction(tx: &rusqlite::Transaction, at: &Location, name: &str) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO instructions (signature, instruction_index, slot, block_time, name)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![at.signature, at.index, at.slot, at.block_time, name],
+    )?;
+    Ok(())
+}
+
+// Apply one event. Inserts are idempotent so replaying the same transactions is harmless.
+pub fn apply_event(tx: &rusqlite::Transaction, at: &Location, event: &PoolEvent) -> Result<()> {
+    match event {
+        PoolEvent::PoolCreated(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO pools
+                 (pool, pool_id, creator, token_a_mint, token_b_mint, lp_mint, fee_percentage, created_slot, created_at,
+                  status_slot, fee_slot)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?8, ?8)",
+                params![
+                    e.pool.to_string(),
+                    hex::encode(e.pool_id),
+                    e.creator.to_string(),
+                    e.token_a_mint.to_string(),
+                    e.token_b_mint.to_string(),
+                    e.lp_mint.to_string(),
+                    e.fee_percentage,
+                    at.slot,
+                    at.block_time,
+                ],
+            )?;
+        }
+        PoolEvent::LiquidityAdded(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO deposits
+                 (signature, event_index, slot, block_time, pool, user,
+                  amount_a_desired, amount_b_desired, amount_a, amount_b, lp_tokens_minted)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    at.signature,
+                    at.index,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.user.to_string(),
+                    e.amount_a_desired,
+                    e.amount_b_desired,
+                    e.amount_a,
+                    e.amount_b,
+                    e.lp_tokens_minted,
+                ],
+            )?;
+        }
+        PoolEvent::LiquidityRemoved(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO withdrawals
+                 (signature, event_index, slot, block_time, pool, user, amount_a, amount_b, lp_tokens_burned)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    at.signature,
+                    at.index,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.user.to_string(),
+                    e.amount_a,
+                    e.amount_b,
+                    e.lp_tokens_burned,
+                ],
+            )?;
+        }
+        PoolEvent::Swap(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO swaps
+                 (signature, event_index, slot, block_time, pool, user, a_to_b, amount_in, amount_out, protocol_fee)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    at.signature,
+                    at.index,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.user.to_string(),
+                    e.a_to_b,
+                    e.amount_in,
+                    e.amount_out,
+                    e.protocol_fee,
+                ],
+            )?;
+        }
+        PoolEvent::FeesCollected(e) => {
+            // Totals are summed from this table (see the pool_fees view), so a replay cannot count a collection twice
+            tx.execute(
+                "INSERT OR IGNORE INTO fee_collections (signature, event_index, slot, block_time, pool, admin, amount_a, amount_b)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    at.signature,
+                    at.index,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.admin.to_string(),
+                    e.amount_a,
+                    e.amount_b,
+                ],
+            )?;
+        }
+        PoolEvent::VaaProcessed(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO bridge_requests
+                 (vaa_hash, signature, slot, block_time, pool, emitter_chain, emitter_address, sequence, operation_code, status)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    hex::encode(e.vaa_hash),
+                    at.signature,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.emitter_chain,
+                    hex::encode(e.emitter_address),
+                    e.sequence,
+                    e.operation_code,
+                    status_name(&e.status),
+                ],
+            )?;
+        }
+        PoolEvent::StatusChanged(e) => {
+            // Latest change wins; an older slot never overwrites a newer one on replay
+            tx.execute(
+                "UPDATE pools SET status = ?2, status_slot = ?3 WHERE pool = ?1 AND ?3 >= status_slot",
+                params![e.pool.to_string(), e.new_status, at.slot],
+            )?;
+        }
+        PoolEvent::FeeChanged(e) => {
+            // Latest change wins, as for the status
+            tx.execute(
+                "UPDATE pools SET fee_percentage = ?2, fee_slot = ?3 WHERE pool = ?1 AND ?3 >= fee_slot",
+                params![e.pool.to_string(), e.new_fee, at.slot],
+            )?;
+        }
+        PoolEvent::LpBridgedOut(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO lp_migrations
+                 (signature, event_index, slot, block_time, pool, user, sui_recipient, lp_amount, reserve_a_share, reserve_b_share)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    at.signature,
+                    at.index,
+                    at.slot,
+                    at.block_time,
+                    e.pool.to_string(),
+                    e.user.to_string(),
+                    hex::encode(e.sui_recipient),
+                    e.lp_amount,
+                    e.reserve_a_share,
+                    e.reserve_b_share,
+                ],
+            )?;
+        }
+        PoolEvent::EmitterOrderingSet(e) => {
+            // Latest registration wins; an older slot never overwrites a newer one on replay
+            tx.execute(
+                "INSERT INTO emitters (emitter_chain, emitter_address, ordered, next_expected_sequence, updated_slot)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT (emitter_chain, emitter_address) DO UPDATE SET
+                     ordered = excluded.ordered,
+                     next_expected_sequence = excluded.next_expected_sequence,
+                     updated_slot = excluded.updated_slot
+                 WHERE excluded.updated_slot >= emitters.updated_slot",
+                params![
+                    e.emitter_chain,
+                    hex::encode(e.emitter_address),
+                    e.ordered,
+                    e.next_expected_sequence,
+                    at.slot,
+                ],
+            )?;
+            let value = format!(
+                "chain={} address={} ordered={} next_sequence={}",
+                e.emitter_chain,
+                hex::encode(e.emitter_address),
+                e.ordered,
+                e.next_expected_sequence,
+            );
+            insert_config_update(tx, at, &e.admin, "emitter_ordering", &value)?;
+        }
+        PoolEvent::WormholeSettingsUpdated(e) => {
+            let value = format!("batch_nonce={} consistency_levels={:?}", e.batch_nonce, e.consistency_levels);
+            insert_config_update(tx, at, &e.admin, "wormhole_settings", &value)?;
+        }
+        PoolEvent::RelayerSettingsUpdated(e) => {
+            insert_config_update(
+                tx,
+                at,
+                &e.admin,
+                "rent_reimbursement_lamports",
+                &e.new_rent_reimbursement_lamports.to_string(),
+            )?;
+        }
+        PoolEvent::DriftThresholdUpdated(e) => {
+            insert_config_update(tx, at, &e.admin, "max_price_drift_bps", &e.new_max_price_drift_bps.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+// Config changes are kept as a log; the current value of a setting is its latest row
+fn insert_config_update(tx: &rusqlite::Transaction, at: &Location, admin: &Pubkey, setting: &str, value: &str) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO config_updates (signature, event_index, slot, block_time, admin, setting, value)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![at.signature, at.index, at.slot, at.block_time, admin.to_string(), setting, value],
+    )?;
+    Ok(())
+}
+
+fn status_name(status: &BridgeStatus) -> &'static str {
+    match status {
+        BridgeStatus::Pending => "pending",
+        BridgeStatus::Completed => "completed",
+        BridgeStatus::Failed => "failed",
+    }
+}
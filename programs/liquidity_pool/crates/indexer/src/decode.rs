@@ -0,0 +1,86 @@
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use liquidity_pool::events::{
+    DriftThresholdUpdated, EmitterOrderingSet, FeeChanged, FeesCollected, LiquidityAdded, LiquidityRemoved,
+    LpBridgedOut, PoolCreated, RelayerSettingsUpdated, StatusChanged, Swap, VaaProcessed, WormholeSettingsUpdated,
+};
+use liquidity_pool::instruction as ix;
+
+// Events emitted by the program (see programs/liquidity_pool/src/events.rs)
+pub enum PoolEvent {
+    PoolCreated(PoolCreated),
+    LiquidityAdded(LiquidityAdded),
+    LiquidityRemoved(LiquidityRemoved),
+    Swap(Swap),
+    FeesCollected(FeesCollected),
+    VaaProcessed(VaaProcessed),
+    StatusChanged(StatusChanged),
+    FeeChanged(FeeChanged),
+    LpBridgedOut(LpBridgedOut),
+    EmitterOrderingSet(EmitterOrderingSet),
+    WormholeSettingsUpdated(WormholeSettingsUpdated),
+    RelayerSettingsUpdated(RelayerSettingsUpdated),
+    DriftThresholdUpdated(DriftThresholdUpdated),
+}
+
+// Decode the data of an emit_cpi! self-CPI: event tag, event discriminator, Borsh event
+pub fn decode_event(data: &[u8]) -> Option<PoolEvent> {
+    let rest = data.strip_prefix(&EVENT_IX_TAG_LE[..])?;
+    if rest.len() < 8 {
+        return None;
+    }
+    let (discriminator, payload) = rest.split_at(8);
+
+    parse::<PoolCreated>(discriminator, payload).map(PoolEvent::PoolCreated)
+        .or_else(|| parse::<LiquidityAdded>(discriminator, payload).map(PoolEvent::LiquidityAdded))
+        .or_else(|| parse::<LiquidityRemoved>(discriminator, payload).map(PoolEvent::LiquidityRemoved))
+        .or_else(|| parse::<Swap>(discriminator, payload).map(PoolEvent::Swap))
+        .or_else(|| parse::<FeesCollected>(discriminator, payload).map(PoolEvent::FeesCollected))
+        .or_else(|| parse::<VaaProcessed>(discriminator, payload).map(PoolEvent::VaaProcessed))
+        .or_else(|| parse::<StatusChanged>(discriminator, payload).map(PoolEvent::StatusChanged))
+        .or_else(|| parse::<FeeChanged>(discriminator, payload).map(PoolEvent::FeeChanged))
+        .or_else(|| parse::<LpBridgedOut>(discriminator, payload).map(PoolEvent::LpBridgedOut))
+        .or_else(|| parse::<EmitterOrderingSet>(discriminator, payload).map(PoolEvent::EmitterOrderingSet))
+        .or_else(|| parse::<WormholeSettingsUpdated>(discriminator, payload).map(PoolEvent::WormholeSettingsUpdated))
+        .or_else(|| parse::<RelayerSettingsUpdated>(discriminator, payload).map(PoolEvent::RelayerSettingsUpdated))
+        .or_else(|| parse::<DriftThresholdUpdated>(discriminator, payload).map(PoolEvent::DriftThresholdUpdated))
+}
+
+fn parse<T: AnchorDeserialize + Discriminator>(discriminator: &[u8], payload: &[u8]) -> Option<T> {
+    if discriminator != &T::DISCRIMINATOR[..] {
+        return None;
+    }
+    T::try_from_slice(payload).ok()
+}
+
+// Name of a top-level program instruction, from its Anchor discriminator
+pub fn instruction_name(data: &[u8]) -> Option<&'static str> {
+    let discriminator = data.get(..8)?;
+    INSTRUCTIONS
+        .iter()
+        .find(|(known, _)| discriminator == &known[..])
+        .map(|(_, name)| *name)
+}
+
+const INSTRUCTIONS: &[([u8; 8], &str)] = &[
+    (ix::CreatePool::DISCRIMINATOR, "create_pool"),
+    (ix::AddLiquidity::DISCRIMINATOR, "add_liquidity"),
+    (ix::RemoveLiquidity::DISCRIMINATOR, "remove_liquidity"),
+    (ix::Swap::DISCRIMINATOR, "swap"),
+    (ix::AddLiquiditySingleSided::DISCRIMINATOR, "add_liquidity_single_sided"),
+    (ix::RemoveLiquiditySingleSided::DISCRIMINATOR, "remove_liquidity_single_sided"),
+    (ix::ProcessAddLiquidityVaa::DISCRIMINATOR, "process_add_liquidity_vaa"),
+    (ix::ProcessRemoveLiquidityVaa::DISCRIMINATOR, "process_remove_liquidity_vaa"),
+    (ix::ProcessSwapVaa::DISCRIMINATOR, "process_swap_vaa"),
+    (ix::ProcessReserveSnapshotVaa::DISCRIMINATOR, "process_reserve_snapshot_vaa"),
+    (ix::BridgeLpOut::DISCRIMINATOR, "bridge_lp_out"),
+    (ix::ProcessLpMigrationVaa::DISCRIMINATOR, "process_lp_migration_vaa"),
+    (ix::InitializeConfig::DISCRIMINATOR, "initialize_config"),
+    (ix::UpdateWormholeSettings::DISCRIMINATOR, "update_wormhole_settings"),
+    (ix::UpdateRelayerSettings::DISCRIMINATOR, "update_relayer_settings"),
+    (ix::SetEmitterOrdering::DISCRIMINATOR, "set_emitter_ordering"),
+    (ix::UpdateDriftThreshold::DISCRIMINATOR, "update_drift_threshold"),
+    (ix::SetPoolStatus::DISCRIMINATOR, "set_pool_status"),
+    (ix::CollectFees::DISCRIMINATOR, "collect_fees"),
+    (ix::SetFee::DISCRIMINATOR, "set_fee"),
+];
@@ -0,0 +1,120 @@
+//! Replays liquidity pool transactions and maintains the pool history in SQLite.
+//!
+//! Events are read from the `emit_cpi!` self-CPIs recorded in the transaction, so the indexer
+//! does not depend on program logs (which the runtime may truncate).
+
+mod db;
+mod decode;
+mod source;
+
+use std::{str::FromStr, thread, time::Duration};
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+use db::{Database, Location};
+
+#[derive(Parser)]
+#[command(about = "Index liquidity pool events and instructions into SQLite")]
+struct Cli {
+    /// SQLite database file (created if missing)
+    #[arg(long, default_value = "omnisphere.sqlite")]
+    db: String,
+
+    /// Liquidity pool program id
+    #[arg(long, default_value_t = liquidity_pool::ID.to_string())]
+    program_id: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Replay transactions from a JSON dump ("json" encoded getTransaction responses)
+    Dump {
+        /// JSON array or JSON-lines file
+        file: String,
+    },
+    /// Fetch the program's transactions from an RPC node (e.g. a local validator)
+    Rpc {
+        #[arg(long, default_value = "http://127.0.0.1:8899")]
+        url: String,
+
+        /// Keep polling for new transactions
+        #[arg(long)]
+        follow: bool,
+
+        /// Seconds between polls with --follow
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let program_id = Pubkey::from_str(&cli.program_id)?;
+    let mut db = Database::open(&cli.db)?;
+
+    match cli.command {
+        Command::Dump { file } => {
+            let transactions = source::read_dump(&file)?;
+            let indexed = index_all(&mut db, &transactions, &program_id)?;
+            println!("Indexed {} of {} transactions from {}", indexed, transactions.len(), file);
+        }
+        Command::Rpc { url, follow, interval } => {
+            let client = RpcClient::new_with_commitment(url, CommitmentConfig::confirmed());
+            loop {
+                let cursor = db.cursor()?;
+                let transactions = source::fetch_from_rpc(&client, &program_id, cursor.as_deref())?;
+                let indexed = index_all(&mut db, &transactions, &program_id)?;
+                if !transactions.is_empty() {
+                    println!("Indexed {} of {} new transactions", indexed, transactions.len());
+                }
+                if !follow {
+                    break;
+                }
+                thread::sleep(Duration::from_secs(interval));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Index transactions in order, advancing the RPC cursor after each one. Returns how many succeeded.
+fn index_all(
+    db: &mut Database,
+    transactions: &[EncodedConfirmedTransactionWithStatusMeta],
+    program_id: &Pubkey,
+) -> Result<usize> {
+    let mut indexed = 0;
+    for transaction in transactions {
+        let Some(tx) = source::to_indexed(transaction, program_id)? else {
+            continue;
+        };
+        db.transaction(|sql| {
+            let mut instruction_index = 0;
+            let mut event_index = 0;
+            for instruction in &tx.instructions {
+                if instruction.inner {
+                    if let Some(event) = decode::decode_event(&instruction.data) {
+                        let at = Location { signature: &tx.signature, slot: tx.slot, block_time: tx.block_time, index: event_index };
+                        db::apply_event(sql, &at, &event)?;
+                        event_index += 1;
+                    }
+                } else {
+                    let name = decode::instruction_name(&instruction.data).unwrap_or("unknown");
+                    let at = Location { signature: &tx.signature, slot: tx.slot, block_time: tx.block_time, index: instruction_index };
+                    db::insert_instruction(sql, &at, name)?;
+                    instruction_index += 1;
+                }
+            }
+            db::set_cursor(sql, &tx.signature)
+        })?;
+        indexed += 1;
+    }
+    Ok(indexed)
+}
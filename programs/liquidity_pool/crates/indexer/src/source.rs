@@ -0,0 +1,157 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    UiCompiledInstruction, UiInstruction, UiMessage, UiTransactionEncoding,
+};
+
+// An instruction executed by the liquidity pool program, top-level or through CPI
+// (emit_cpi! events are self-CPIs, so they show up as inner instructions)
+pub struct ProgramInstruction {
+    pub data: Vec<u8>,
+    pub accounts: Vec<Pubkey>,
+    pub inner: bool,
+}
+
+// A successful transaction reduced to the program's instructions, in execution order
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub instructions: Vec<ProgramInstruction>,
+}
+
+// Read transactions from a JSON dump: either a JSON array or one transaction per line,
+// each in the `getTransaction` format with "json" encoding.
+pub fn read_dump(path: &str) -> Result<Vec<EncodedConfirmedTransactionWithStatusMeta>> {
+    let contents = std::fs::read_to_string(path).with_context(|| format!("reading {}", path))?;
+    if contents.trim_start().starts_with('[') {
+        return serde_json::from_str(&contents).with_context(|| format!("parsing {}", path));
+    }
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| serde_json::from_str(line).with_context(|| format!("parsing {} line {}", path, i + 1)))
+        .collect()
+}
+
+// Fetch every transaction that touched `program_id` after `until` (oldest first)
+pub fn fetch_from_rpc(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    until: Option<&str>,
+) -> Result<Vec<EncodedConfirmedTransactionWithStatusMeta>> {
+    let until = until.map(Signature::from_str).transpose()?;
+    let mut signatures = Vec::new();
+    let mut before = None;
+    loop {
+        let page = client.get_signatures_for_address_with_config(
+            program_id,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )?;
+        let Some(last) = page.last() else { break };
+        before = Some(Signature::from_str(&last.signature)?);
+        signatures.extend(page.into_iter().filter(|s| s.err.is_none()).map(|s| s.signature));
+    }
+
+    // RPC returns newest first
+    signatures.reverse();
+    signatures
+        .iter()
+        .map(|signature| {
+            client
+                .get_transaction_with_config(
+                    &Signature::from_str(signature)?,
+                    RpcTransactionConfig {
+                        encoding: Some(UiTransactionEncoding::Json),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                        max_supported_transaction_version: Some(0),
+                    },
+                )
+                .with_context(|| format!("fetching transaction {}", signature))
+        })
+        .collect()
+}
+
+// Extract the program's instructions from a transaction. Failed transactions return None.
+pub fn to_indexed(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    program_id: &Pubkey,
+) -> Result<Option<IndexedTransaction>> {
+    let meta = tx.transaction.meta.as_ref().ok_or_else(|| anyhow!("transaction without status meta"))?;
+    if meta.err.is_some() {
+        return Ok(None);
+    }
+    let EncodedTransaction::Json(ui_transaction) = &tx.transaction.transaction else {
+        return Err(anyhow!("transaction must use the \"json\" encoding"));
+    };
+    let UiMessage::Raw(message) = &ui_transaction.message else {
+        return Err(anyhow!("transaction message must not be jsonParsed"));
+    };
+    let signature = ui_transaction.signatures.first().cloned().ok_or_else(|| anyhow!("unsigned transaction"))?;
+
+    // Static keys followed by the keys loaded from address lookup tables
+    let mut keys = message
+        .account_keys
+        .iter()
+        .map(|key| Pubkey::from_str(key))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+        for key in loaded.writable.iter().chain(loaded.readonly.iter()) {
+            keys.push(Pubkey::from_str(key)?);
+        }
+    }
+
+    let inner_instructions = match &meta.inner_instructions {
+        OptionSerializer::Some(inner) => inner.as_slice(),
+        _ => &[],
+    };
+
+    let mut instructions = Vec::new();
+    for (index, instruction) in message.instructions.iter().enumerate() {
+        push_if_program(&mut instructions, instruction, &keys, program_id, false)?;
+        for inner in inner_instructions.iter().filter(|inner| inner.index as usize == index) {
+            for inner_instruction in &inner.instructions {
+                if let UiInstruction::Compiled(compiled) = inner_instruction {
+                    push_if_program(&mut instructions, compiled, &keys, program_id, true)?;
+                }
+            }
+        }
+    }
+
+    Ok(Some(IndexedTransaction {
+        signature,
+        slot: tx.slot,
+        block_time: tx.block_time,
+        instructions,
+    }))
+}
+
+fn push_if_program(
+    instructions: &mut Vec<ProgramInstruction>,
+    compiled: &UiCompiledInstruction,
+    keys: &[Pubkey],
+    program_id: &Pubkey,
+    inner: bool,
+) -> Result<()> {
+    let key = |index: u8| keys.get(index as usize).copied().ok_or_else(|| anyhow!("account index {} out of range", index));
+    if key(compiled.program_id_index)? != *program_id {
+        return Ok(());
+    }
+    instructions.push(ProgramInstruction {
+        data: bs58::decode(&compiled.data).into_vec()?,
+        accounts: compiled.accounts.iter().map(|&index| key(index)).collect::<Result<_>>()?,
+        inner,
+    });
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! Relayer for the liquidity pool program: watches a VAA source for the registered emitters,
+//! posts each VAA to the Wormhole core bridge and submits it to the matching `process_*_vaa`
+//! instruction. Emitters are relayed in sequence order from a persisted cursor.
+
+mod relay;
+mod source;
+mod state;
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use liquidity_pool_client::vaa::VaaInfo;
+use solana_sdk::signature::read_keypair_file;
+
+use relay::{Outcome, ReceiverAccounts, Relayer};
+use source::{DirectorySource, Emitter, GuardianSource, VaaSource};
+use state::State;
+
+#[derive(Parser)]
+#[command(about = "Relay Wormhole VAAs to the liquidity pool program")]
+struct Cli {
+    /// Solana RPC endpoint
+    #[arg(long, short = 'u', default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Relayer keypair (pays transactions, receives relayer fees)
+    #[arg(long, short = 'k')]
+    keypair: PathBuf,
+
+    /// Registered emitter as <chain>:<address hex> (repeatable)
+    #[arg(long = "emitter", required = true)]
+    emitters: Vec<Emitter>,
+
+    /// Accounts of a receiver program named by bridge completions, as <program>=<account>[:w],...
+    /// (repeatable). VAAs naming a receiver program without accounts here are not relayed.
+    #[arg(long = "receiver")]
+    receivers: Vec<ReceiverAccounts>,
+
+    /// Cursor file (next sequence per emitter)
+    #[arg(long, default_value = "relayer-state.json")]
+    state: PathBuf,
+
+    /// First sequence relayed for an emitter without a cursor
+    #[arg(long, default_value_t = 0)]
+    start_sequence: u64,
+
+    /// Seconds between polls once every emitter is caught up
+    #[arg(long, default_value_t = 5)]
+    interval: u64,
+
+    /// Attempts per VAA before moving on to the next emitter
+    #[arg(long, default_value_t = 5)]
+    max_retries: u32,
+
+    /// Advance past a VAA that still fails after all retries instead of retrying it next poll
+    #[arg(long)]
+    skip_failed: bool,
+
+    #[command(subcommand)]
+    source: Source,
+}
+
+#[derive(Subcommand)]
+enum Source {
+    /// Poll a guardian REST endpoint (/v1/signed_vaa/{chain}/{emitter}/{sequence})
+    Guardian { url: String },
+    /// Read VAA files (raw or hex) from a directory, for local testing
+    Dir { path: PathBuf },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let payer = read_keypair_file(&cli.keypair)
+        .map_err(|e| anyhow!("reading keypair {}: {}", cli.keypair.display(), e))?;
+    let relayer = Relayer::new(cli.url.clone(), payer, cli.receivers.clone());
+    let mut state = State::load(cli.state.clone())?;
+    let mut source: Box<dyn VaaSource> = match &cli.source {
+        Source::Guardian { url } => Box::new(GuardianSource::new(url.clone())?),
+        Source::Dir { path } => Box::new(DirectorySource::new(path.clone())),
+    };
+
+    loop {
+        for emitter in &cli.emitters {
+            if let Err(error) = relay_emitter(&cli, &relayer, source.as_mut(), &mut state, emitter) {
+                eprintln!("[{}] {:#}", emitter, error);
+            }
+        }
+        thread::sleep(Duration::from_secs(cli.interval));
+    }
+}
+
+// Relay every available VAA of `emitter` from its cursor on
+fn relay_emitter(
+    cli: &Cli,
+    relayer: &Relayer,
+    source: &mut dyn VaaSource,
+    state: &mut State,
+    emitter: &Emitter,
+) -> Result<()> {
+    loop {
+        let sequence = state.next_sequence(emitter, cli.start_sequence);
+        let Some(vaa) = source.fetch(emitter, sequence)? else {
+            return Ok(()); // Caught up
+        };
+        let info = VaaInfo::parse(&vaa).map_err(|e| anyhow!("invalid VAA at sequence {}: {}", sequence, e))?;
+        if info.emitter_chain != emitter.chain || info.emitter_address != emitter.address || info.sequence != sequence {
+            return Err(anyhow!("source returned a VAA of {}:{} sequence {}", info.emitter_chain, hex::encode(info.emitter_address), info.sequence));
+        }
+
+        match with_retries(cli.max_retries, || relayer.relay(&vaa)) {
+            Ok(Outcome::Submitted(signature)) => println!("[{}] sequence {} relayed: {}", emitter, sequence, signature),
+            Ok(Outcome::AlreadyProcessed) => println!("[{}] sequence {} already processed", emitter, sequence),
+            Err(error) if cli.skip_failed => eprintln!("[{}] skipping sequence {}: {:#}", emitter, sequence, error),
+            Err(error) => return Err(error.context(format!("sequence {}", sequence))),
+        }
+        state.advance(emitter, sequence)?;
+    }
+}
+
+// Retry with exponential backoff (1s, 2s, 4s, ...)
+fn with_retries<T>(max_attempts: u32, mut attempt: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut delay = Duration::from_secs(1);
+    let mut attempt_number = 1;
+    loop {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt_number >= max_attempts => return Err(error),
+            Err(error) => {
+                eprintln!("attempt {} failed: {:#}, retrying in {:?}", attempt_number, error, delay);
+                thread::sleep(delay);
+                delay *= 2;
+                attempt_number += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use anchor_lang::AnchorDeserialize;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::payloads::BridgeOperationCode;
+use liquidity_pool_client::core_bridge::{self, GuardianSetData};
+use liquidity_pool_client::{accounts, instructions, pda, pda::PoolAddresses, vaa::VaaInfo};
+use solana_client::{client_error::ClientError, rpc_client::RpcClient};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account_idempotent};
+
+pub enum Outcome {
+    Submitted(Signature),
+    AlreadyProcessed,
+}
+
+// Accounts a receiver program expects after the delivered token accounts (see receiver.rs), given on
+// the command line as `<program>=<account>[:w],...` (`:w` marks a writable account)
+#[derive(Clone, Debug)]
+pub struct ReceiverAccounts {
+    pub program: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+}
+
+impl FromStr for ReceiverAccounts {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (program, accounts) = value.split_once('=').ok_or_else(|| anyhow!("expected <program>=<account>[:w],..."))?;
+        let accounts = accounts
+            .split(',')
+            .filter(|account| !account.is_empty())
+            .map(|account| match account.strip_suffix(":w") {
+                Some(writable) => Ok(AccountMeta::new(Pubkey::from_str(writable)?, false)),
+                None => Ok(AccountMeta::new_readonly(Pubkey::from_str(account)?, false)),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { program: Pubkey::from_str(program)?, accounts })
+    }
+}
+
+pub struct Relayer {
+    client: RpcClient,
+    payer: Keypair,
+    receivers: HashMap<Pubkey, Vec<AccountMeta>>,
+}
+
+impl Relayer {
+    pub fn new(url: String, payer: Keypair, receivers: Vec<ReceiverAccounts>) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+            payer,
+            receivers: receivers.into_iter().map(|receiver| (receiver.program, receiver.accounts)).collect(),
+        }
+    }
+
+    // Post the VAA to the core bridge if needed, then submit it to the matching process_*_vaa instruction
+    pub fn relay(&self, vaa_bytes: &[u8]) -> Result<Outcome> {
+        let vaa = VaaInfo::parse(vaa_bytes).map_err(|e| anyhow!("invalid VAA: {}", e))?;
+
+        // Every operation except reserve snapshots leaves a BridgeRequest record behind
+        let is_snapshot = vaa.operation_code == BridgeOperationCode::ReserveSnapshot as u8;
+        if !is_snapshot && self.exists(&pda::bridge_request(&vaa.hash))? {
+            return Ok(Outcome::AlreadyProcessed);
+        }
+
+        // Checked before posting: the callback can't be made without the receiver program's accounts
+        let receiver_accounts: &[AccountMeta] = match vaa.receiver_program().map_err(|e| anyhow!("{}", e))? {
+            Some(program) => self.receivers.get(&program).ok_or_else(|| {
+                anyhow!("VAA names receiver program {} but no --receiver accounts are configured for it", program)
+            })?,
+            None => &[][..],
+        };
+
+        let posted_vaa = pda::posted_vaa(&vaa.hash);
+        let signature_set = if self.exists(&posted_vaa)? {
+            accounts::decode_posted_vaa_signature_set(&self.client.get_account_data(&posted_vaa)?)
+                .map_err(|e| anyhow!("decoding posted VAA: {}", e))?
+        } else {
+            self.post_vaa(vaa_bytes, &vaa)?
+        };
+
+        let pool_id = vaa.pool_id().map_err(|e| anyhow!("{}", e))?;
+        let ledger = accounts::decode_ledger(&self.client.get_account_data(&pda::ledger(&pool_id))?)
+            .map_err(|e| anyhow!("decoding ledger: {}", e))?;
+        let pool = accounts::decode_pool(&self.client.get_account_data(&ledger.pool)?)
+            .map_err(|e| anyhow!("decoding pool: {}", e))?;
+        let addresses = PoolAddresses::from_pool(&pool);
+
+        let mut instruction = instructions::process_vaa(&self.payer.pubkey(), &posted_vaa, &signature_set, &vaa, &addresses)
+            .map_err(|e| anyhow!("{}", e))?;
+        instruction.accounts.extend_from_slice(receiver_accounts);
+        let mut transaction = self.relayer_fee_accounts(&instruction, &addresses);
+        transaction.push(instruction);
+
+        match self.send(&transaction, &[]) {
+            Ok(signature) => Ok(Outcome::Submitted(signature)),
+            // A newer snapshot already landed: nothing left to do for this one
+            Err(error) if is_snapshot && is_program_error(&error, ErrorCode::StaleReserveSnapshot) => {
+                Ok(Outcome::AlreadyProcessed)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    // Verify the signatures and post the VAA; returns the signature set recording them
+    fn post_vaa(&self, vaa_bytes: &[u8], vaa: &VaaInfo) -> Result<Pubkey> {
+        let guardian_set_data = self
+            .client
+            .get_account_data(&pda::guardian_set(vaa.guardian_set_index))
+            .with_context(|| format!("fetching guardian set {}", vaa.guardian_set_index))?;
+        let guardian_set = GuardianSetData::deserialize(&mut &guardian_set_data[..])?;
+
+        let signature_set = Keypair::new();
+        let payer = self.payer.pubkey();
+        let batches =
+            core_bridge::verify_signatures_instructions(&payer, &signature_set.pubkey(), vaa_bytes, vaa, &guardian_set)
+                .map_err(|e| anyhow!("{}", e))?;
+        for batch in batches {
+            self.send(&batch, &[&signature_set])?;
+        }
+        let post = core_bridge::post_vaa_instruction(&payer, &signature_set.pubkey(), vaa_bytes, vaa)
+            .map_err(|e| anyhow!("{}", e))?;
+        self.send(&[post], &[])?;
+        Ok(signature_set.pubkey())
+    }
+
+    // Idempotent creation of the payer's token accounts receiving relayer fees
+    fn relayer_fee_accounts(&self, instruction: &Instruction, pool: &PoolAddresses) -> Vec<Instruction> {
+        let payer = self.payer.pubkey();
+        [pool.token_a_mint, pool.token_b_mint, pool.lp_mint]
+            .iter()
+            .filter(|mint| {
+                let address = get_associated_token_address(&payer, mint);
+                instruction.accounts.iter().any(|meta| meta.pubkey == address)
+            })
+            .map(|mint| create_associated_token_account_idempotent(&payer, &payer, mint, &anchor_spl::token::ID))
+            .collect()
+    }
+
+    fn exists(&self, address: &Pubkey) -> Result<bool> {
+        Ok(self
+            .client
+            .get_account_with_commitment(address, CommitmentConfig::confirmed())?
+            .value
+            .is_some())
+    }
+
+    fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> std::result::Result<Signature, ClientError> {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&self.payer.pubkey()), &all_signers, blockhash);
+        self.client.send_and_confirm_transaction(&transaction)
+    }
+}
+
+fn is_program_error(error: &ClientError, code: ErrorCode) -> bool {
+    matches!(
+        error.get_transaction_error(),
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(custom))) if custom == u32::from(code)
+    )
+}
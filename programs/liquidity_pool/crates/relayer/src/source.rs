@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use liquidity_pool_client::vaa::VaaInfo;
+use serde::Deserialize;
+
+// A registered emitter, given on the command line as `<chain>:<address hex>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Emitter {
+    pub chain: u16,
+    pub address: [u8; 32],
+}
+
+impl FromStr for Emitter {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (chain, address) = value.split_once(':').ok_or_else(|| anyhow!("expected <chain>:<address hex>"))?;
+        let address = hex::decode(address.trim_start_matches("0x"))?
+            .try_into()
+            .map_err(|_| anyhow!("emitter address must be 32 bytes"))?;
+        Ok(Self { chain: chain.parse()?, address })
+    }
+}
+
+impl fmt::Display for Emitter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.chain, hex::encode(self.address))
+    }
+}
+
+// Where signed VAAs come from. VAAs are requested by emitter and sequence so the relayer can
+// work through each emitter in order from its persisted cursor.
+pub trait VaaSource {
+    fn fetch(&mut self, emitter: &Emitter, sequence: u64) -> Result<Option<Vec<u8>>>;
+}
+
+// Signed VAAs served by a guardian (or any API exposing the guardian REST route)
+pub struct GuardianSource {
+    base_url: String,
+    client: reqwest::blocking::Client,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedVaaResponse {
+    vaa_bytes: String, // base64
+}
+
+impl GuardianSource {
+    pub fn new(base_url: String) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder().timeout(Duration::from_secs(10)).build()?;
+        Ok(Self { base_url: base_url.trim_end_matches('/').to_string(), client })
+    }
+}
+
+impl VaaSource for GuardianSource {
+    fn fetch(&mut self, emitter: &Emitter, sequence: u64) -> Result<Option<Vec<u8>>> {
+        let url = format!(
+            "{}/v1/signed_vaa/{}/{}/{}",
+            self.base_url,
+            emitter.chain,
+            hex::encode(emitter.address),
+            sequence
+        );
+        let response = self.client.get(&url).send().with_context(|| format!("GET {}", url))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None); // Not signed (yet)
+        }
+        let body: SignedVaaResponse = response.error_for_status()?.json()?;
+        Ok(Some(base64::engine::general_purpose::STANDARD.decode(body.vaa_bytes)?))
+    }
+}
+
+// Local stand-in: VAA files (raw bytes or hex) dropped in a directory, rescanned on every poll
+pub struct DirectorySource {
+    path: PathBuf,
+    index: HashMap<(Emitter, u64), PathBuf>,
+}
+
+impl DirectorySource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, index: HashMap::new() }
+    }
+
+    fn rescan(&mut self) -> Result<()> {
+        self.index.clear();
+        for entry in std::fs::read_dir(&self.path).with_context(|| format!("reading {}", self.path.display()))? {
+            let path = entry?.path();
+            if !path.is_file() {
+                continue;
+            }
+            // Files that are not VAAs are ignored
+            let Ok(vaa) = read_vaa_file(&path).and_then(|bytes| VaaInfo::parse(&bytes).map_err(|e| anyhow!("{}", e)))
+            else {
+                continue;
+            };
+            let emitter = Emitter { chain: vaa.emitter_chain, address: vaa.emitter_address };
+            self.index.insert((emitter, vaa.sequence), path);
+        }
+        Ok(())
+    }
+}
+
+impl VaaSource for DirectorySource {
+    fn fetch(&mut self, emitter: &Emitter, sequence: u64) -> Result<Option<Vec<u8>>> {
+        if !self.index.contains_key(&(*emitter, sequence)) {
+            self.rescan()?;
+        }
+        self.index.get(&(*emitter, sequence)).map(|path| read_vaa_file(path)).transpose()
+    }
+}
+
+// Raw VAA bytes, or the same bytes hex encoded
+pub fn read_vaa_file(path: &PathBuf) -> Result<Vec<u8>> {
+    let contents = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(match std::str::from_utf8(&contents) {
+        Ok(text) => hex::decode(text.trim().trim_start_matches("0x")).unwrap_or(contents),
+        Err(_) => contents,
+    })
+}
@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::source::Emitter;
+
+// Persisted cursor: next sequence to relay for each emitter
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    next_sequence: BTreeMap<String, u64>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl State {
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let mut state: State = if path.exists() {
+            let contents = std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            serde_json::from_str(&contents).with_context(|| format!("parsing {}", path.display()))?
+        } else {
+            State::default()
+        };
+        state.path = path;
+        Ok(state)
+    }
+
+    pub fn next_sequence(&self, emitter: &Emitter, start: u64) -> u64 {
+        self.next_sequence.get(&emitter.to_string()).copied().unwrap_or(start)
+    }
+
+    // Advance and persist (written to a temporary file first so a crash never truncates the cursor)
+    pub fn advance(&mut self, emitter: &Emitter, sequence: u64) -> Result<()> {
+        self.next_sequence.insert(emitter.to_string(), sequence + 1);
+        let temporary = self.path.with_extension("tmp");
+        std::fs::write(&temporary, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
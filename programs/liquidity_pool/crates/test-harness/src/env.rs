@@ -0,0 +1,296 @@
+use anchor_lang::solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_pack::Pack};
+use anchor_lang::{AccountDeserialize, AnchorDeserialize};
+use liquidity_pool_client::core_bridge::{self, GuardianSetData};
+use liquidity_pool_client::{accounts, instructions, pda, pda::PoolAddresses, vaa::VaaInfo, WORMHOLE_PROGRAM_ID};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account_idempotent};
+
+use crate::guardians::{MockGuardians, MockVaa};
+use crate::receiver::{self, MOCK_RECEIVER_PROGRAM_ID};
+use crate::wormhole;
+
+// Guardians of the mock core bridge when none are given (mainnet set size)
+pub const DEFAULT_GUARDIAN_COUNT: u8 = 19;
+// Deadline used by the harness helpers (never expires)
+pub const NO_DEADLINE: i64 = i64::MAX;
+
+// The program runs natively inside solana-program-test. Anchor's `entry` ties the accounts slice to the
+// 'info lifetime of its elements, which a `processor!` function doesn't get: the slice is copied and
+// leaked (a few accounts per instruction, for the life of the test) so that it lives as long as the accounts.
+fn process_liquidity_pool_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts: &[AccountInfo] = Box::leak(accounts.to_vec().into_boxed_slice());
+    liquidity_pool::entry(program_id, accounts, data)
+}
+
+// ProgramData account of the deployed program, as the upgradeable loader stores it
+fn program_data_account(upgrade_authority: &Pubkey) -> Account {
+    let state = UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address: Some(*upgrade_authority) };
+    Account::new_data(1_000_000_000, &state, &bpf_loader_upgradeable::id()).unwrap()
+}
+
+// Bank running the liquidity pool program next to the mocked core bridge. The payer is the config admin.
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+    pub guardians: MockGuardians,
+}
+
+impl TestEnv {
+    pub async fn start() -> Self {
+        Self::start_with_guardians(MockGuardians::new(0, DEFAULT_GUARDIAN_COUNT)).await
+    }
+
+    pub async fn start_with_guardians(guardians: MockGuardians) -> Self {
+        let mut env = Self::start_without_config(guardians).await;
+        let admin = env.payer();
+        env.send(&[instructions::initialize_config(&admin)], &[]).await.expect("initialize config");
+        env
+    }
+
+    // Bank before initialize_config, with the payer as the program's upgrade authority
+    pub async fn start_without_config(guardians: MockGuardians) -> Self {
+        let mut program_test = ProgramTest::new("liquidity_pool", liquidity_pool::ID, processor!(process_liquidity_pool_instruction));
+        program_test.prefer_bpf(false);
+        program_test.add_program(
+            "wormhole_core_bridge",
+            WORMHOLE_PROGRAM_ID,
+            processor!(wormhole::process_core_bridge_instruction),
+        );
+        program_test.add_program(
+            "mock_receiver",
+            MOCK_RECEIVER_PROGRAM_ID,
+            processor!(receiver::process_receiver_instruction),
+        );
+        program_test.add_account(pda::wormhole_bridge(), wormhole::bridge_account(guardians.set_index));
+        program_test.add_account(
+            pda::guardian_set(guardians.set_index),
+            wormhole::guardian_set_account(guardians.set_index, guardians.addresses(), 0),
+        );
+
+        let mut context = program_test.start_with_context().await;
+        let upgrade_authority = context.payer.pubkey();
+        context.set_account(&pda::program_data(), &AccountSharedData::from(program_data_account(&upgrade_authority)));
+        Self { context, guardians }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    // Sign with the payer (and `signers`) and process the transaction.
+    // Every transaction gets a fresh blockhash, so sending the same instructions twice
+    // (e.g. a replayed VAA) is processed again instead of being deduplicated by the bank.
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let blockhash = self.context.get_new_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    pub async fn fund(&mut self, address: &Pubkey, lamports: u64) {
+        let payer = self.payer();
+        self.send(&[system_instruction::transfer(&payer, address, lamports)], &[]).await.expect("fund account");
+    }
+
+    // New wallet funded with 1 SOL
+    pub async fn new_user(&mut self) -> Keypair {
+        let user = Keypair::new();
+        self.fund(&user.pubkey(), 1_000_000_000).await;
+        user
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.context.banks_client.get_account(*address).await.unwrap().expect("account exists");
+        T::try_deserialize(&mut &account.data[..]).expect("account decodes")
+    }
+
+    pub async fn account_exists(&mut self, address: &Pubkey) -> bool {
+        self.context.banks_client.get_account(*address).await.unwrap().is_some()
+    }
+
+    // Lamports held by an account, 0 when it doesn't exist
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    // Balance of a token account, 0 when it doesn't exist
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        match self.context.banks_client.get_account(*address).await.unwrap() {
+            Some(account) => spl_token::state::Account::unpack(&account.data).expect("token account").amount,
+            None => 0,
+        }
+    }
+
+    // New mint with the payer as mint authority
+    pub async fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::id(), &mint.pubkey(), &payer, None, decimals).unwrap(),
+        ];
+        self.send(&instructions, &[&mint]).await.expect("create mint");
+        mint.pubkey()
+    }
+
+    // The owner's ATA for `mint`, created if missing
+    pub async fn create_token_account(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let payer = self.payer();
+        self.send(&[create_associated_token_account_idempotent(&payer, owner, mint, &spl_token::id())], &[])
+            .await
+            .expect("create token account");
+        get_associated_token_address(owner, mint)
+    }
+
+    // Mint `amount` to the owner's ATA (created if missing) and return the ATA
+    pub async fn mint_to(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let payer = self.payer();
+        let token_account = self.create_token_account(owner, mint).await;
+        self.send(&[spl_token::instruction::mint_to(&spl_token::id(), mint, &token_account, &payer, &[], amount).unwrap()], &[])
+            .await
+            .expect("mint tokens");
+        token_account
+    }
+
+    // Pool between two new mints (6 decimals)
+    pub async fn create_pool(&mut self, fee_percentage: u64, pool_id: [u8; 32]) -> PoolAddresses {
+        let token_a_mint = self.create_mint(6).await;
+        let token_b_mint = self.create_mint(6).await;
+        let payer = self.payer();
+        self.send(&[instructions::create_pool(&payer, &token_a_mint, &token_b_mint, fee_percentage, pool_id)], &[])
+            .await
+            .expect("create pool");
+        PoolAddresses::derive(&token_a_mint, &token_b_mint, &pool_id)
+    }
+
+    // Local deposit by the payer (tokens are minted to it first)
+    pub async fn add_liquidity(&mut self, pool: &PoolAddresses, amount_a: u64, amount_b: u64) {
+        let payer = self.payer();
+        self.mint_to(&pool.token_a_mint, &payer, amount_a).await;
+        self.mint_to(&pool.token_b_mint, &payer, amount_b).await;
+        self.send(&[instructions::add_liquidity(&payer, pool, amount_a, amount_b, 0, 0, NO_DEADLINE)], &[])
+            .await
+            .expect("add liquidity");
+    }
+
+    // Register a foreign emitter (creates its sequence tracker)
+    pub async fn register_emitter(
+        &mut self,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        ordered: bool,
+        next_expected_sequence: u64,
+    ) {
+        let admin = self.payer();
+        self.send(
+            &[instructions::set_emitter_ordering(&admin, emitter_chain, emitter_address, ordered, next_expected_sequence)],
+            &[],
+        )
+        .await
+        .expect("register emitter");
+    }
+
+    // Replace the current guardian set with `guardians`, as a core bridge governance VAA would.
+    // The previous set expires at `previous_expiration_time` (Unix timestamp) and is returned.
+    pub fn rotate_guardian_set(&mut self, guardians: MockGuardians, previous_expiration_time: u32) -> MockGuardians {
+        let previous = std::mem::replace(&mut self.guardians, guardians);
+        let accounts = [
+            (
+                pda::guardian_set(previous.set_index),
+                wormhole::guardian_set_account(previous.set_index, previous.addresses(), previous_expiration_time),
+            ),
+            (
+                pda::guardian_set(self.guardians.set_index),
+                wormhole::guardian_set_account(self.guardians.set_index, self.guardians.addresses(), 0),
+            ),
+            (pda::wormhole_bridge(), wormhole::bridge_account(self.guardians.set_index)),
+        ];
+        for (address, account) in accounts {
+            self.context.set_account(&address, &AccountSharedData::from(account));
+        }
+        previous
+    }
+
+    // Guardian set `index` as stored by the core bridge
+    pub async fn guardian_set(&mut self, index: u32) -> GuardianSetData {
+        let account = self.context.banks_client.get_account(pda::guardian_set(index)).await.unwrap();
+        GuardianSetData::try_from_slice(&account.expect("guardian set exists").data).expect("guardian set decodes")
+    }
+
+    // Post a signed VAA through the core bridge (verify_signatures, then post_vaa), checking its
+    // signatures against the guardian set it names
+    pub async fn post_vaa(&mut self, vaa: &[u8]) -> Result<VaaInfo, BanksClientError> {
+        let info = VaaInfo::parse(vaa).expect("well-formed VAA");
+        let guardian_set = self.guardian_set(info.guardian_set_index).await;
+        self.post_vaa_with_guardian_set(vaa, &guardian_set).await
+    }
+
+    // Post a signed VAA, building the secp256k1 instructions from the keys of `guardian_set`
+    // (to submit VAAs whose guardian set isn't on chain)
+    pub async fn post_vaa_with_guardian_set(
+        &mut self,
+        vaa: &[u8],
+        guardian_set: &GuardianSetData,
+    ) -> Result<VaaInfo, BanksClientError> {
+        let info = VaaInfo::parse(vaa).expect("well-formed VAA");
+        let signature_set = Keypair::new();
+        let payer = self.payer();
+        let batches =
+            core_bridge::verify_signatures_instructions(&payer, &signature_set.pubkey(), vaa, &info, guardian_set)
+                .expect("guardians of the VAA are in the set");
+        for batch in batches {
+            self.send(&batch, &[&signature_set]).await?;
+        }
+        let post = core_bridge::post_vaa_instruction(&payer, &signature_set.pubkey(), vaa, &info).unwrap();
+        self.send(&[post], &[]).await?;
+        Ok(info)
+    }
+
+    // Signature set named by the PostedVAA of `vaa_hash`
+    pub async fn signature_set(&mut self, vaa_hash: &[u8; 32]) -> Pubkey {
+        let account = self.context.banks_client.get_account(pda::posted_vaa(vaa_hash)).await.unwrap();
+        accounts::decode_posted_vaa_signature_set(&account.expect("VAA is posted").data).expect("posted VAA decodes")
+    }
+
+    // Submit the `process_*_vaa` instruction of an already posted VAA with the payer as relayer
+    pub async fn process_posted_vaa(&mut self, info: &VaaInfo, pool: &PoolAddresses) -> Result<(), BanksClientError> {
+        let signature_set = self.signature_set(&info.hash).await;
+        let payer = self.payer();
+        let instruction = instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, info, pool)
+            .expect("supported operation");
+        self.send(&[instruction], &[]).await
+    }
+
+    // Post a raw VAA and submit the matching `process_*_vaa` instruction with the payer as relayer
+    pub async fn process_signed_vaa(&mut self, vaa: &[u8], pool: &PoolAddresses) -> Result<(), BanksClientError> {
+        let info = self.post_vaa(vaa).await?;
+        self.process_posted_vaa(&info, pool).await
+    }
+
+    // Sign `vaa` with the mock guardians, post it and process it
+    pub async fn process_vaa(&mut self, vaa: &MockVaa, pool: &PoolAddresses) -> Result<(), BanksClientError> {
+        let signed = vaa.signed_by(&self.guardians);
+        self.process_signed_vaa(&signed, pool).await
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::solana_program::keccak;
+use anchor_lang::AnchorSerialize;
+use liquidity_pool::payloads::BridgeOperationCode;
+
+// Locally generated guardian keys signing VAAs the way the Wormhole guardians do:
+// each guardian signs keccak256(keccak256(body)) with its secp256k1 key.
+
+// Wormhole chain ID of Sui, the emitter chain of every inbound bridge operation
+pub const SUI_CHAIN_ID: u16 = 21;
+// Emitter address of the mock Sui pool package
+pub const SUI_EMITTER: [u8; 32] = [0x5u8; 32];
+
+pub struct MockGuardians {
+    pub set_index: u32,
+    keys: Vec<libsecp256k1::SecretKey>,
+}
+
+impl MockGuardians {
+    // `count` deterministic guardian keys forming guardian set `set_index`
+    pub fn new(set_index: u32, count: u8) -> Self {
+        let keys = (0..count)
+            .map(|i| {
+                let seed = keccak::hashv(&[b"mock-guardian", &set_index.to_be_bytes(), &[i]]).to_bytes();
+                libsecp256k1::SecretKey::parse(&seed).expect("guardian seed is a valid secp256k1 key")
+            })
+            .collect();
+        Self { set_index, keys }
+    }
+
+    // Ethereum-style addresses of the guardians, as stored in the core bridge guardian set account
+    pub fn addresses(&self) -> Vec<[u8; 20]> {
+        self.keys
+            .iter()
+            .map(|key| {
+                let public_key = libsecp256k1::PublicKey::from_secret_key(key).serialize();
+                keccak::hash(&public_key[1..]).to_bytes()[12..].try_into().unwrap()
+            })
+            .collect()
+    }
+
+    // Raw signed VAA: header (version, guardian set index, signatures) followed by `body`
+    pub fn sign(&self, body: &[u8]) -> Vec<u8> {
+        let digest = keccak::hash(&keccak::hash(body).to_bytes()).to_bytes();
+        let message = libsecp256k1::Message::parse(&digest);
+
+        let mut vaa = vec![1u8];
+        vaa.extend_from_slice(&self.set_index.to_be_bytes());
+        vaa.push(self.keys.len() as u8);
+        for (index, key) in self.keys.iter().enumerate() {
+            let (signature, recovery_id) = libsecp256k1::sign(&message, key);
+            vaa.push(index as u8);
+            vaa.extend_from_slice(&signature.serialize());
+            vaa.push(recovery_id.serialize());
+        }
+        vaa.extend_from_slice(body);
+        vaa
+    }
+}
+
+// Body of a VAA carrying a bridge operation (payload = operation code + borsh payload)
+#[derive(Clone, Debug)]
+pub struct MockVaa {
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+impl MockVaa {
+    // Operation emitted by the mock Sui pool
+    pub fn new(sequence: u64, operation: BridgeOperationCode, payload: &impl AnchorSerialize) -> Self {
+        let mut bytes = vec![operation as u8];
+        bytes.extend_from_slice(&payload.try_to_vec().unwrap());
+        Self {
+            timestamp: 1_700_000_000,
+            nonce: 0,
+            emitter_chain: SUI_CHAIN_ID,
+            emitter_address: SUI_EMITTER,
+            sequence,
+            consistency_level: 1,
+            payload: bytes,
+        }
+    }
+
+    pub fn with_emitter(mut self, emitter_chain: u16, emitter_address: [u8; 32]) -> Self {
+        self.emitter_chain = emitter_chain;
+        self.emitter_address = emitter_address;
+        self
+    }
+
+    pub fn body(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(51 + self.payload.len());
+        body.extend_from_slice(&self.timestamp.to_be_bytes());
+        body.extend_from_slice(&self.nonce.to_be_bytes());
+        body.extend_from_slice(&self.emitter_chain.to_be_bytes());
+        body.extend_from_slice(&self.emitter_address);
+        body.extend_from_slice(&self.sequence.to_be_bytes());
+        body.push(self.consistency_level);
+        body.extend_from_slice(&self.payload);
+        body
+    }
+
+    pub fn signed_by(&self, guardians: &MockGuardians) -> Vec<u8> {
+        guardians.sign(&self.body())
+    }
+}
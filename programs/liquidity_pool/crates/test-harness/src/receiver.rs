@@ -0,0 +1,24 @@
+use anchor_lang::solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey, pubkey::Pubkey,
+};
+use liquidity_pool::receiver::DELIVERY_CALLBACK_DISCRIMINATOR;
+
+// Mock receiver program of bridge completions: accepts `on_omnisphere_delivery` callbacks whose
+// first account is a signing authority that holds nothing, as the pool's delivery authority does.
+
+pub const MOCK_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("Receiver1111111111111111111111111111111111");
+
+pub fn process_receiver_instruction(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    if !data.starts_with(&DELIVERY_CALLBACK_DISCRIMINATOR) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let authority = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if authority.lamports() != 0 || !authority.data_is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    msg!("Mock receiver: delivery signed by {}", authority.key);
+    Ok(())
+}
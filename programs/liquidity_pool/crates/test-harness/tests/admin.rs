@@ -0,0 +1,221 @@
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::math;
+use liquidity_pool::state::{Config, ConsistencyLevel, OperationConsistency, OutboundOperation, Pool, SequenceTracker};
+use liquidity_pool_client::instructions;
+use liquidity_pool_client::pda::{self, PoolAddresses};
+use omnisphere_test_harness::{
+    assert_custom_error, assert_program_error, MockGuardians, TestEnv, NO_DEADLINE, SUI_CHAIN_ID, SUI_EMITTER,
+};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+// Admin instructions: config updates, emitter registration, pool fee/status and fee collection.
+// The payer of the test bank is the config admin.
+
+const POOL_ID: [u8; 32] = [4u8; 32];
+const FEE_BPS: u64 = 30;
+
+async fn funded_pool() -> (TestEnv, PoolAddresses) {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    env.add_liquidity(&pool, 1_000_000, 1_000_000).await;
+    (env, pool)
+}
+
+// Swap of `amount` A by a new trader, accruing protocol fees in token A
+async fn swap_a_to_b(env: &mut TestEnv, pool: &PoolAddresses, amount: u64) {
+    let user = env.new_user().await;
+    env.mint_to(&pool.token_a_mint, &user.pubkey(), amount).await;
+    env.create_token_account(&user.pubkey(), &pool.token_b_mint).await;
+    let swap = instructions::swap(&user.pubkey(), pool, amount, 0, true, NO_DEADLINE);
+    env.send(&[swap], &[&user]).await.unwrap();
+}
+
+#[tokio::test]
+async fn initialize_config_sets_defaults_once() {
+    let mut env = TestEnv::start().await;
+    let admin = env.payer();
+
+    let config: Config = env.account(&pda::config()).await;
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.rent_reimbursement_lamports, 0);
+    assert_eq!(config.max_price_drift_bps, 100);
+    assert_eq!(config.consistency_for(OutboundOperation::LpMigration), ConsistencyLevel::Finalized);
+
+    // The config PDA already exists
+    let account_already_in_use = 0;
+    assert_custom_error(env.send(&[instructions::initialize_config(&admin)], &[]).await, account_already_in_use);
+}
+
+#[tokio::test]
+async fn initialize_config_requires_upgrade_authority() {
+    let mut env = TestEnv::start_without_config(MockGuardians::new(0, 19)).await;
+
+    // Front-running the deployment doesn't make the caller admin
+    let intruder = env.new_user().await;
+    assert_program_error(
+        env.send(&[instructions::initialize_config(&intruder.pubkey())], &[&intruder]).await,
+        ErrorCode::InvalidAuthority,
+    );
+    assert!(!env.account_exists(&pda::config()).await);
+
+    let upgrade_authority = env.payer();
+    env.send(&[instructions::initialize_config(&upgrade_authority)], &[]).await.unwrap();
+    let config: Config = env.account(&pda::config()).await;
+    assert_eq!(config.admin, upgrade_authority);
+}
+
+#[tokio::test]
+async fn admin_instructions_reject_other_signers() {
+    let (mut env, pool) = funded_pool().await;
+    let intruder = env.new_user().await;
+    let key = intruder.pubkey();
+    let receiver_a = env.create_token_account(&key, &pool.token_a_mint).await;
+    let receiver_b = env.create_token_account(&key, &pool.token_b_mint).await;
+    let (mint_a, mint_b) = (env.create_mint(6).await, env.create_mint(6).await);
+
+    let instructions: Vec<Instruction> = vec![
+        instructions::create_pool(&key, &mint_a, &mint_b, FEE_BPS, [5u8; 32]),
+        instructions::update_wormhole_settings(&key, Some(7), vec![]),
+        instructions::update_relayer_settings(&key, 5_000),
+        instructions::set_emitter_ordering(&key, SUI_CHAIN_ID, SUI_EMITTER, true, 0),
+        instructions::update_drift_threshold(&key, 1),
+        instructions::set_pool_status(&key, &pool.pool, 1),
+        instructions::set_fee(&key, &pool.pool, 100),
+        instructions::collect_fees(&key, &pool, &receiver_a, &receiver_b),
+    ];
+    for instruction in instructions {
+        assert_program_error(env.send(&[instruction], &[&intruder]).await, ErrorCode::InvalidAuthority);
+    }
+}
+
+#[tokio::test]
+async fn config_updates_are_stored() {
+    let mut env = TestEnv::start().await;
+    let admin = env.payer();
+
+    let levels = vec![OperationConsistency {
+        operation: OutboundOperation::SwapRefund,
+        level: ConsistencyLevel::Confirmed,
+    }];
+    let updates = [
+        instructions::update_wormhole_settings(&admin, Some(7), levels),
+        instructions::update_relayer_settings(&admin, 5_000),
+        instructions::update_drift_threshold(&admin, 250),
+    ];
+    env.send(&updates, &[]).await.unwrap();
+
+    let config: Config = env.account(&pda::config()).await;
+    assert_eq!(config.batch_nonce, 7);
+    assert_eq!(config.consistency_for(OutboundOperation::SwapRefund), ConsistencyLevel::Confirmed);
+    assert_eq!(config.consistency_for(OutboundOperation::AddLiquidity), ConsistencyLevel::Finalized);
+    assert_eq!(config.rent_reimbursement_lamports, 5_000);
+    assert_eq!(config.max_price_drift_bps, 250);
+
+    // No batch nonce keeps the current one
+    env.send(&[instructions::update_wormhole_settings(&admin, None, vec![])], &[]).await.unwrap();
+    let config: Config = env.account(&pda::config()).await;
+    assert_eq!(config.batch_nonce, 7);
+}
+
+#[tokio::test]
+async fn set_emitter_ordering_registers_and_updates_tracker() {
+    let mut env = TestEnv::start().await;
+    let tracker = pda::sequence_tracker(SUI_CHAIN_ID, &SUI_EMITTER);
+
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, false, 0).await;
+    let state: SequenceTracker = env.account(&tracker).await;
+    assert_eq!(state.emitter_chain, SUI_CHAIN_ID);
+    assert_eq!(state.emitter_address, SUI_EMITTER);
+    assert!(!state.ordered);
+
+    // Registering again updates the existing tracker
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, true, 42).await;
+    let state: SequenceTracker = env.account(&tracker).await;
+    assert!(state.ordered);
+    assert_eq!(state.next_expected_sequence, 42);
+}
+
+#[tokio::test]
+async fn set_fee_updates_pool_and_rejects_full_fee() {
+    let (mut env, pool) = funded_pool().await;
+    let admin = env.payer();
+
+    env.send(&[instructions::set_fee(&admin, &pool.pool, 100)], &[]).await.unwrap();
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.fee_percentage, 100);
+
+    assert_program_error(
+        env.send(&[instructions::set_fee(&admin, &pool.pool, math::FEE_DENOMINATOR)], &[]).await,
+        ErrorCode::InvalidFee,
+    );
+}
+
+#[tokio::test]
+async fn create_pool_rejects_full_fee() {
+    let mut env = TestEnv::start().await;
+    let admin = env.payer();
+    let (mint_a, mint_b) = (env.create_mint(6).await, env.create_mint(6).await);
+
+    assert_program_error(
+        env.send(&[instructions::create_pool(&admin, &mint_a, &mint_b, math::FEE_DENOMINATOR, POOL_ID)], &[]).await,
+        ErrorCode::InvalidFee,
+    );
+}
+
+#[tokio::test]
+async fn set_pool_status_updates_pool_and_rejects_unknown_status() {
+    let (mut env, pool) = funded_pool().await;
+    let admin = env.payer();
+
+    env.send(&[instructions::set_pool_status(&admin, &pool.pool, 2)], &[]).await.unwrap();
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.status, 2);
+
+    assert_program_error(
+        env.send(&[instructions::set_pool_status(&admin, &pool.pool, 3)], &[]).await,
+        ErrorCode::InvalidPoolStatus,
+    );
+}
+
+#[tokio::test]
+async fn collect_fees_transfers_accrued_protocol_fees() {
+    let (mut env, pool) = funded_pool().await;
+    swap_a_to_b(&mut env, &pool, 100_000).await;
+
+    let accrued: Pool = env.account(&pool.pool).await;
+    assert_eq!(accrued.protocol_fee_a, math::protocol_fee(100_000, FEE_BPS).unwrap());
+    let vault_a = env.token_balance(&pool.token_a_account).await;
+
+    let admin = env.payer();
+    let receiver_a = get_associated_token_address(&admin, &pool.token_a_mint);
+    let receiver_b = get_associated_token_address(&admin, &pool.token_b_mint);
+    let receiver_a_before = env.token_balance(&receiver_a).await;
+    env.send(&[instructions::collect_fees(&admin, &pool, &receiver_a, &receiver_b)], &[]).await.unwrap();
+
+    assert_eq!(env.token_balance(&receiver_a).await, receiver_a_before + accrued.protocol_fee_a);
+    assert_eq!(env.token_balance(&pool.token_a_account).await, vault_a - accrued.protocol_fee_a);
+
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.protocol_fee_a, 0);
+    assert_eq!(state.protocol_fee_b, 0);
+}
+
+#[tokio::test]
+async fn collect_fees_rejects_receivers_of_other_mints() {
+    let (mut env, pool) = funded_pool().await;
+    let admin = env.payer();
+    let receiver_a = get_associated_token_address(&admin, &pool.token_a_mint);
+    let receiver_b = get_associated_token_address(&admin, &pool.token_b_mint);
+    let other_mint = env.create_mint(6).await;
+    let stray = env.create_token_account(&Keypair::new().pubkey(), &other_mint).await;
+
+    assert_program_error(
+        env.send(&[instructions::collect_fees(&admin, &pool, &stray, &receiver_b)], &[]).await,
+        ErrorCode::InvalidMint,
+    );
+    assert_program_error(
+        env.send(&[instructions::collect_fees(&admin, &pool, &receiver_a, &stray)], &[]).await,
+        ErrorCode::InvalidMint,
+    );
+}
@@ -0,0 +1,235 @@
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::payloads::{AddLiquidityCompletionPayload, BridgeOperationCode, RemoveLiquidityCompletionPayload};
+use liquidity_pool::state::{BridgeRequest, PoolLedger};
+use liquidity_pool_client::instructions;
+use liquidity_pool_client::pda::{self, PoolAddresses};
+use omnisphere_test_harness::{
+    assert_custom_error, assert_program_error, MockVaa, TestEnv, MOCK_RECEIVER_PROGRAM_ID, NO_DEADLINE,
+    SUI_CHAIN_ID, SUI_EMITTER,
+};
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+const POOL_ID: [u8; 32] = [7u8; 32];
+const FEE_BPS: u64 = 30;
+
+// Pool with the mock Sui pool registered as an unordered emitter
+async fn setup() -> (TestEnv, PoolAddresses) {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, false, 0).await;
+    (env, pool)
+}
+
+fn add_payload(recipient: &Pubkey, lp_amount: u64) -> AddLiquidityCompletionPayload {
+    AddLiquidityCompletionPayload {
+        recipient_address: recipient.to_bytes(),
+        lp_amount_to_mint: lp_amount,
+        original_pool_id: POOL_ID,
+        relayer_fee: 0,
+        target_token_account: None,
+        receiver_program: None,
+        amount_a_deposited: 5_000,
+        amount_b_deposited: 2_000,
+    }
+}
+
+fn add_completion(sequence: u64, recipient: &Pubkey, lp_amount: u64) -> MockVaa {
+    MockVaa::new(sequence, BridgeOperationCode::AddLiquidityCompletion, &add_payload(recipient, lp_amount))
+}
+
+// Move `lp_amount` of the payer's LP to Sui (remote LP supply)
+async fn bridge_payer_lp_out(env: &mut TestEnv, pool: &PoolAddresses, lp_amount: u64) {
+    let payer = env.payer();
+    let message = Keypair::new();
+    let bridge_out = instructions::bridge_lp_out(&payer, pool, &message.pubkey(), lp_amount, [1u8; 32], NO_DEADLINE);
+    env.send(&[bridge_out], &[&message]).await.expect("bridge LP out");
+}
+
+#[tokio::test]
+async fn add_completion_mints_lp_and_updates_ledger() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    let vaa = add_completion(0, &recipient, 1_000);
+    env.process_vaa(&vaa, &pool).await.unwrap();
+
+    let recipient_lp = get_associated_token_address(&recipient, &pool.lp_mint);
+    assert_eq!(env.token_balance(&recipient_lp).await, 1_000);
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.local_lp_supply, 1_000);
+    assert_eq!(ledger.remote_reserve_a, 5_000);
+    assert_eq!(ledger.remote_reserve_b, 2_000);
+
+    let signed = vaa.signed_by(&env.guardians);
+    let hash = env.post_vaa(&signed).await.expect("post VAA").hash;
+    assert!(env.account_exists(&pda::bridge_request(&hash)).await);
+}
+
+#[tokio::test]
+async fn receiver_callback_is_signed_by_the_delivery_authority() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    // The mock receiver only accepts a signer holding nothing: the pool authority would be rejected
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.receiver_program = Some(MOCK_RECEIVER_PROGRAM_ID.to_bytes());
+    let vaa = MockVaa::new(0, BridgeOperationCode::AddLiquidityCompletion, &payload);
+    env.process_vaa(&vaa, &pool).await.unwrap();
+
+    let recipient_lp = get_associated_token_address(&recipient, &pool.lp_mint);
+    assert_eq!(env.token_balance(&recipient_lp).await, 1_000);
+    assert!(!env.account_exists(&pda::delivery_authority(&pool.pool)).await);
+}
+
+#[tokio::test]
+async fn relayer_is_reimbursed_only_the_rent_it_paid() {
+    let (mut env, pool) = setup().await;
+    let admin = env.payer();
+    env.send(&[instructions::update_relayer_settings(&admin, 1_000_000_000)], &[]).await.unwrap();
+    env.fund(&pda::config(), 1_000_000_000).await;
+    let rent = Rent::default();
+    let bridge_request_rent = rent.minimum_balance(BridgeRequest::SIZE);
+    let token_account_rent = rent.minimum_balance(spl_token::state::Account::LEN);
+
+    // First completion creates the bridge request and the recipient's LP account
+    let recipient = Keypair::new().pubkey();
+    let fund_before = env.lamports(&pda::config()).await;
+    env.process_vaa(&add_completion(0, &recipient, 1_000), &pool).await.unwrap();
+    assert_eq!(fund_before - env.lamports(&pda::config()).await, bridge_request_rent + token_account_rent);
+
+    // The LP account exists now: only the bridge request is paid for
+    let fund_before = env.lamports(&pda::config()).await;
+    env.process_vaa(&add_completion(1, &recipient, 1_000), &pool).await.unwrap();
+    assert_eq!(fund_before - env.lamports(&pda::config()).await, bridge_request_rent);
+}
+
+#[tokio::test]
+async fn remove_completion_pays_out_local_reserves() {
+    let (mut env, pool) = setup().await;
+    env.add_liquidity(&pool, 1_000_000, 1_000_000).await;
+
+    // LP moved to Sui by the payer, part of which is then withdrawn there
+    bridge_payer_lp_out(&mut env, &pool, 500_000).await;
+
+    let recipient = Keypair::new().pubkey();
+    let removal = MockVaa::new(
+        0,
+        BridgeOperationCode::RemoveLiquidityCompletion,
+        &RemoveLiquidityCompletionPayload {
+            recipient_address: recipient.to_bytes(),
+            amount_a_to_transfer: 100_000,
+            amount_b_to_transfer: 100_000,
+            original_pool_id: POOL_ID,
+            relayer_fee_a: 0,
+            relayer_fee_b: 0,
+            target_token_a_account: None,
+            target_token_b_account: None,
+            receiver_program: None,
+            lp_amount_burned: 100_000,
+        },
+    );
+    env.process_vaa(&removal, &pool).await.unwrap();
+
+    let recipient_a = get_associated_token_address(&recipient, &pool.token_a_mint);
+    let recipient_b = get_associated_token_address(&recipient, &pool.token_b_mint);
+    assert_eq!(env.token_balance(&recipient_a).await, 100_000);
+    assert_eq!(env.token_balance(&recipient_b).await, 100_000);
+    assert_eq!(env.token_balance(&pool.token_a_account).await, 900_000);
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.remote_lp_supply, 400_000);
+    assert_eq!(ledger.local_reserve_a, 900_000);
+    assert_eq!(ledger.local_reserve_b, 900_000);
+}
+
+#[tokio::test]
+async fn deposit_into_drained_pool_fails_with_pool_drained() {
+    let (mut env, pool) = setup().await;
+    env.add_liquidity(&pool, 1_000_000, 1_000_000).await;
+
+    // Sui only receives token B, then a Sui withdrawal releases all of the Solana token A
+    let sui_deposit = AddLiquidityCompletionPayload {
+        amount_a_deposited: 0,
+        amount_b_deposited: 2_000,
+        ..add_payload(&Keypair::new().pubkey(), 1_000)
+    };
+    env.process_vaa(&MockVaa::new(0, BridgeOperationCode::AddLiquidityCompletion, &sui_deposit), &pool)
+        .await
+        .unwrap();
+    bridge_payer_lp_out(&mut env, &pool, 1_000).await;
+
+    let recipient = Keypair::new().pubkey();
+    let removal = MockVaa::new(
+        1,
+        BridgeOperationCode::RemoveLiquidityCompletion,
+        &RemoveLiquidityCompletionPayload {
+            recipient_address: recipient.to_bytes(),
+            amount_a_to_transfer: 1_000_000,
+            amount_b_to_transfer: 100_000,
+            original_pool_id: POOL_ID,
+            relayer_fee_a: 0,
+            relayer_fee_b: 0,
+            target_token_a_account: None,
+            target_token_b_account: None,
+            receiver_program: None,
+            lp_amount_burned: 1_000,
+        },
+    );
+    env.process_vaa(&removal, &pool).await.unwrap();
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.total_reserve_a().unwrap(), 0);
+    assert!(ledger.total_lp_supply().unwrap() > 0);
+
+    // LP is still outstanding: the deposit ratio is undefined
+    let user = env.new_user().await;
+    env.mint_to(&pool.token_a_mint, &user.pubkey(), 10_000).await;
+    env.mint_to(&pool.token_b_mint, &user.pubkey(), 10_000).await;
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 0, 0, NO_DEADLINE);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::PoolDrained);
+}
+
+#[tokio::test]
+async fn replayed_vaa_is_rejected() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let vaa = add_completion(0, &recipient, 1_000);
+    env.process_vaa(&vaa, &pool).await.unwrap();
+
+    // The BridgeRequest PDA of the VAA already exists: the system program refuses to create it again
+    let account_already_in_use = 0;
+    assert_custom_error(env.process_vaa(&vaa, &pool).await, account_already_in_use);
+
+    let recipient_lp = get_associated_token_address(&recipient, &pool.lp_mint);
+    assert_eq!(env.token_balance(&recipient_lp).await, 1_000);
+}
+
+#[tokio::test]
+async fn vaa_from_unregistered_emitter_is_rejected() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    let foreign_address = add_completion(0, &recipient, 1_000).with_emitter(SUI_CHAIN_ID, [9u8; 32]);
+    assert_program_error(env.process_vaa(&foreign_address, &pool).await, ErrorCode::InvalidEmitterAddress);
+
+    // Same address, other chain
+    let foreign_chain = add_completion(0, &recipient, 1_000).with_emitter(2, SUI_EMITTER);
+    assert_program_error(env.process_vaa(&foreign_chain, &pool).await, ErrorCode::InvalidEmitterAddress);
+}
+
+#[tokio::test]
+async fn ordered_emitter_rejects_skipped_sequence() {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, true, 5).await;
+    let recipient = Keypair::new().pubkey();
+
+    assert_program_error(
+        env.process_vaa(&add_completion(6, &recipient, 1_000), &pool).await,
+        ErrorCode::SequenceOutOfOrder,
+    );
+    env.process_vaa(&add_completion(5, &recipient, 1_000), &pool).await.unwrap();
+    env.process_vaa(&add_completion(6, &recipient, 1_000), &pool).await.unwrap();
+}
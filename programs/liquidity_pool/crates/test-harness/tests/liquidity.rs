@@ -0,0 +1,499 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::math::{self, MINIMUM_LIQUIDITY};
+use liquidity_pool::payloads::{AddLiquidityCompletionPayload, BridgeOperationCode};
+use liquidity_pool::state::{Pool, PoolLedger, TokenSide};
+use liquidity_pool_client::instructions;
+use liquidity_pool_client::pda::PoolAddresses;
+use omnisphere_test_harness::{
+    assert_custom_error, assert_program_error, replace_account, MockVaa, TestEnv, NO_DEADLINE, SUI_CHAIN_ID,
+    SUI_EMITTER,
+};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+// Local liquidity instructions: create_pool, add/remove liquidity (two- and single-sided), swap
+// and bridge_lp_out, with the exact error of every account constraint and `require!`.
+
+const POOL_ID: [u8; 32] = [3u8; 32];
+const FEE_BPS: u64 = 30;
+const INITIAL_RESERVE: u64 = 1_000_000;
+
+// Pool funded by the payer with INITIAL_RESERVE of each token (LP supply INITIAL_RESERVE)
+async fn funded_pool() -> (TestEnv, PoolAddresses) {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    env.add_liquidity(&pool, INITIAL_RESERVE, INITIAL_RESERVE).await;
+    (env, pool)
+}
+
+// Wallet holding `amount` of both pool tokens
+async fn trader(env: &mut TestEnv, pool: &PoolAddresses, amount: u64) -> Keypair {
+    let user = env.new_user().await;
+    env.mint_to(&pool.token_a_mint, &user.pubkey(), amount).await;
+    env.mint_to(&pool.token_b_mint, &user.pubkey(), amount).await;
+    user
+}
+
+// Wallet holding LP tokens from a deposit of `amount` of both tokens
+async fn liquidity_provider(env: &mut TestEnv, pool: &PoolAddresses, amount: u64) -> Keypair {
+    let user = trader(env, pool, amount).await;
+    let deposit = instructions::add_liquidity(&user.pubkey(), pool, amount, amount, 0, 0, NO_DEADLINE);
+    env.send(&[deposit], &[&user]).await.unwrap();
+    user
+}
+
+fn ata(owner: &Keypair, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&owner.pubkey(), mint)
+}
+
+// Token account of `owner` for a mint unrelated to the pool (passing one of the pool token
+// accounts instead would reuse an account already present in the instruction)
+async fn stray_token_account(env: &mut TestEnv, owner: &Keypair) -> Pubkey {
+    let mint = env.create_mint(6).await;
+    env.create_token_account(&owner.pubkey(), &mint).await
+}
+
+// create_pool
+
+#[tokio::test]
+async fn create_pool_initializes_pool_and_ledger() {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.fee_percentage, FEE_BPS);
+    assert_eq!(state.pool_id, POOL_ID);
+    assert_eq!(state.status, 0);
+    assert_eq!(state.lp_mint, pool.lp_mint);
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.pool, pool.pool);
+    assert_eq!(ledger.local_lp_supply, 0);
+
+    // The pool PDA of the mint pair already exists
+    let payer = env.payer();
+    let duplicate = instructions::create_pool(&payer, &pool.token_a_mint, &pool.token_b_mint, FEE_BPS, POOL_ID);
+    let account_already_in_use = 0;
+    assert_custom_error(env.send(&[duplicate], &[]).await, account_already_in_use);
+}
+
+// add_liquidity
+
+#[tokio::test]
+async fn first_deposit_locks_minimum_liquidity() {
+    let (mut env, pool) = funded_pool().await;
+    let payer = env.payer();
+
+    assert_eq!(env.token_balance(&get_associated_token_address(&payer, &pool.lp_mint)).await, INITIAL_RESERVE - MINIMUM_LIQUIDITY);
+    assert_eq!(env.token_balance(&pool.locked_lp_token_account).await, MINIMUM_LIQUIDITY);
+    assert_eq!(env.token_balance(&pool.token_a_account).await, INITIAL_RESERVE);
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.local_lp_supply, INITIAL_RESERVE);
+    assert_eq!(ledger.local_reserve_a, INITIAL_RESERVE);
+    assert_eq!(ledger.local_reserve_b, INITIAL_RESERVE);
+}
+
+#[tokio::test]
+async fn first_deposit_must_exceed_minimum_liquidity() {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    let user = trader(&mut env, &pool, MINIMUM_LIQUIDITY).await;
+
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, MINIMUM_LIQUIDITY, MINIMUM_LIQUIDITY, 0, 0, NO_DEADLINE);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::InsufficientInitialLiquidity);
+}
+
+#[tokio::test]
+async fn add_liquidity_deposits_at_reserve_ratio() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 50_000).await;
+
+    // Token B is capped to the ratio, the surplus stays with the user
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 50_000, 10_000, 10_000, NO_DEADLINE);
+    env.send(&[deposit], &[&user]).await.unwrap();
+
+    assert_eq!(env.token_balance(&ata(&user, &pool.lp_mint)).await, 10_000);
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_a_mint)).await, 40_000);
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_b_mint)).await, 40_000);
+}
+
+#[tokio::test]
+async fn add_liquidity_rejects_slippage() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 50_000).await;
+
+    // Only 10_000 B is taken at the current ratio
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 50_000, 0, 20_000, NO_DEADLINE);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::SlippageExceeded);
+}
+
+#[tokio::test]
+async fn add_liquidity_rejects_expired_deadline() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 0, 0, 0);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::DeadlineExceeded);
+}
+
+#[tokio::test]
+async fn add_liquidity_rejects_wrong_user_token_mint() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+
+    let stray = stray_token_account(&mut env, &user).await;
+
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 0, 0, NO_DEADLINE);
+    let deposit = replace_account(deposit, &ata(&user, &pool.token_a_mint), &stray);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::InvalidMint);
+}
+
+#[tokio::test]
+async fn add_liquidity_rejects_foreign_user_token_account() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+    let other = trader(&mut env, &pool, 10_000).await;
+
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 0, 0, NO_DEADLINE);
+    let deposit = replace_account(deposit, &ata(&user, &pool.token_a_mint), &ata(&other, &pool.token_a_mint));
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::InvalidOwner);
+}
+
+#[tokio::test]
+async fn pool_vaults_are_checked_by_seeds() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+
+    let stray = stray_token_account(&mut env, &user).await;
+
+    // The vault constraints (InvalidPoolTokenAccount) sit behind the PDA seeds check
+    let deposit = instructions::add_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 0, 0, NO_DEADLINE);
+    let deposit = replace_account(deposit, &pool.token_a_account, &stray);
+    assert_custom_error(env.send(&[deposit], &[&user]).await, u32::from(AnchorErrorCode::ConstraintSeeds));
+}
+
+#[tokio::test]
+async fn paused_pool_rejects_user_operations() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+    env.mint_to(&pool.token_a_mint, &user.pubkey(), 10_000).await;
+    env.mint_to(&pool.token_b_mint, &user.pubkey(), 10_000).await;
+
+    let admin = env.payer();
+    env.send(&[instructions::set_pool_status(&admin, &pool.pool, 1)], &[]).await.unwrap();
+
+    let user_key = user.pubkey();
+    let operations = [
+        instructions::add_liquidity(&user_key, &pool, 1_000, 1_000, 0, 0, NO_DEADLINE),
+        instructions::remove_liquidity(&user_key, &pool, 1_000, 0, 0, NO_DEADLINE),
+        instructions::swap(&user_key, &pool, 1_000, 0, true, NO_DEADLINE),
+        instructions::add_liquidity_single_sided(&user_key, &pool, 1_000, 0, TokenSide::A, NO_DEADLINE),
+        instructions::remove_liquidity_single_sided(&user_key, &pool, 1_000, 0, TokenSide::A, NO_DEADLINE),
+    ];
+    for operation in operations {
+        assert_program_error(env.send(&[operation], &[&user]).await, ErrorCode::PoolPaused);
+    }
+
+    let message = Keypair::new();
+    let bridge_out = instructions::bridge_lp_out(&user_key, &pool, &message.pubkey(), 1_000, [1u8; 32], NO_DEADLINE);
+    assert_program_error(env.send(&[bridge_out], &[&user, &message]).await, ErrorCode::PoolPaused);
+
+    // Unpausing restores them
+    env.send(&[instructions::set_pool_status(&admin, &pool.pool, 0)], &[]).await.unwrap();
+    let swap = instructions::swap(&user_key, &pool, 1_000, 0, true, NO_DEADLINE);
+    env.send(&[swap], &[&user]).await.unwrap();
+}
+
+// remove_liquidity
+
+#[tokio::test]
+async fn remove_liquidity_pays_proportional_share() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 100_000).await;
+
+    let withdrawal = instructions::remove_liquidity(&user.pubkey(), &pool, 40_000, 40_000, 40_000, NO_DEADLINE);
+    env.send(&[withdrawal], &[&user]).await.unwrap();
+
+    assert_eq!(env.token_balance(&ata(&user, &pool.lp_mint)).await, 60_000);
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_a_mint)).await, 40_000);
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_b_mint)).await, 40_000);
+
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.local_lp_supply, INITIAL_RESERVE + 60_000);
+    assert_eq!(ledger.local_reserve_a, INITIAL_RESERVE + 60_000);
+}
+
+#[tokio::test]
+async fn remove_liquidity_rejects_zero_amount() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+
+    let withdrawal = instructions::remove_liquidity(&user.pubkey(), &pool, 0, 0, 0, NO_DEADLINE);
+    assert_program_error(env.send(&[withdrawal], &[&user]).await, ErrorCode::ZeroLiquidityBurned);
+}
+
+#[tokio::test]
+async fn remove_liquidity_rejects_more_than_balance() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+
+    let withdrawal = instructions::remove_liquidity(&user.pubkey(), &pool, 10_001, 0, 0, NO_DEADLINE);
+    assert_program_error(env.send(&[withdrawal], &[&user]).await, ErrorCode::InsufficientLpTokens);
+}
+
+#[tokio::test]
+async fn remove_liquidity_rejects_slippage() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+
+    let withdrawal = instructions::remove_liquidity(&user.pubkey(), &pool, 10_000, 10_000, 10_001, NO_DEADLINE);
+    assert_program_error(env.send(&[withdrawal], &[&user]).await, ErrorCode::SlippageExceeded);
+}
+
+#[tokio::test]
+async fn remove_liquidity_rejects_wrong_lp_account() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+    let other = liquidity_provider(&mut env, &pool, 10_000).await;
+    let stray = stray_token_account(&mut env, &user).await;
+    let user_lp = ata(&user, &pool.lp_mint);
+
+    let withdrawal = instructions::remove_liquidity(&user.pubkey(), &pool, 1_000, 0, 0, NO_DEADLINE);
+    let wrong_mint = replace_account(withdrawal.clone(), &user_lp, &stray);
+    assert_program_error(env.send(&[wrong_mint], &[&user]).await, ErrorCode::InvalidMint);
+
+    let wrong_owner = replace_account(withdrawal, &user_lp, &ata(&other, &pool.lp_mint));
+    assert_program_error(env.send(&[wrong_owner], &[&user]).await, ErrorCode::InvalidOwner);
+}
+
+#[tokio::test]
+async fn remove_liquidity_is_limited_to_local_reserves() {
+    let (mut env, pool) = funded_pool().await;
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, false, 0).await;
+
+    // Sui receives 3x the Solana reserves for the same LP amount: the payer's share exceeds the Solana vaults
+    let sui_deposit = MockVaa::new(
+        0,
+        BridgeOperationCode::AddLiquidityCompletion,
+        &AddLiquidityCompletionPayload {
+            recipient_address: Keypair::new().pubkey().to_bytes(),
+            lp_amount_to_mint: INITIAL_RESERVE,
+            original_pool_id: POOL_ID,
+            relayer_fee: 0,
+            target_token_account: None,
+            receiver_program: None,
+            amount_a_deposited: 3 * INITIAL_RESERVE,
+            amount_b_deposited: 3 * INITIAL_RESERVE,
+        },
+    );
+    env.process_vaa(&sui_deposit, &pool).await.unwrap();
+
+    let payer = env.payer();
+    let withdrawal = instructions::remove_liquidity(&payer, &pool, INITIAL_RESERVE - MINIMUM_LIQUIDITY, 0, 0, NO_DEADLINE);
+    assert_program_error(env.send(&[withdrawal], &[]).await, ErrorCode::InsufficientLocalReserves);
+}
+
+// swap
+
+#[tokio::test]
+async fn swap_pays_curve_output_and_accrues_protocol_fee() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+    let expected_out = math::swap_output(10_000, INITIAL_RESERVE, INITIAL_RESERVE, FEE_BPS).unwrap();
+
+    let swap = instructions::swap(&user.pubkey(), &pool, 10_000, expected_out, true, NO_DEADLINE);
+    env.send(&[swap], &[&user]).await.unwrap();
+
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_a_mint)).await, 0);
+    assert_eq!(env.token_balance(&ata(&user, &pool.token_b_mint)).await, 10_000 + expected_out);
+
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.protocol_fee_a, math::protocol_fee(10_000, FEE_BPS).unwrap());
+    assert_eq!(state.protocol_fee_b, 0);
+}
+
+#[tokio::test]
+async fn swap_rejects_zero_amount() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+
+    let swap = instructions::swap(&user.pubkey(), &pool, 0, 0, true, NO_DEADLINE);
+    assert_program_error(env.send(&[swap], &[&user]).await, ErrorCode::ZeroSwapAmount);
+}
+
+#[tokio::test]
+async fn swap_rejects_slippage() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+    let expected_out = math::swap_output(10_000, INITIAL_RESERVE, INITIAL_RESERVE, FEE_BPS).unwrap();
+
+    let swap = instructions::swap(&user.pubkey(), &pool, 10_000, expected_out + 1, false, NO_DEADLINE);
+    assert_program_error(env.send(&[swap], &[&user]).await, ErrorCode::SlippageExceeded);
+}
+
+#[tokio::test]
+async fn swap_rejects_empty_pool() {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    let user = trader(&mut env, &pool, 10_000).await;
+
+    let swap = instructions::swap(&user.pubkey(), &pool, 10_000, 0, true, NO_DEADLINE);
+    assert_program_error(env.send(&[swap], &[&user]).await, ErrorCode::PoolEmpty);
+}
+
+#[tokio::test]
+async fn swap_rejects_wrong_user_token_accounts() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 10_000).await;
+    let other = trader(&mut env, &pool, 10_000).await;
+    let stray = stray_token_account(&mut env, &user).await;
+    let user_b = ata(&user, &pool.token_b_mint);
+
+    let swap = instructions::swap(&user.pubkey(), &pool, 1_000, 0, true, NO_DEADLINE);
+    let wrong_mint = replace_account(swap.clone(), &user_b, &stray);
+    assert_program_error(env.send(&[wrong_mint], &[&user]).await, ErrorCode::InvalidMint);
+
+    let wrong_owner = replace_account(swap, &user_b, &ata(&other, &pool.token_b_mint));
+    assert_program_error(env.send(&[wrong_owner], &[&user]).await, ErrorCode::InvalidOwner);
+}
+
+// add_liquidity_single_sided
+
+#[tokio::test]
+async fn single_sided_deposit_mints_lp() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 20_000).await;
+
+    let deposit = instructions::add_liquidity_single_sided(&user.pubkey(), &pool, 20_000, 1, TokenSide::A, NO_DEADLINE);
+    env.send(&[deposit], &[&user]).await.unwrap();
+
+    // Roughly half the deposit is swapped, so the LP is worth a bit less than 10_000 of each token (9_934)
+    let lp = env.token_balance(&ata(&user, &pool.lp_mint)).await;
+    assert!(lp > 9_900 && lp < 10_000, "minted {}", lp);
+    assert!(env.token_balance(&ata(&user, &pool.token_a_mint)).await <= 1);
+
+    // The swap leg accrues the protocol fee like a plain swap
+    let swap_amount = math::zap_swap_amount(20_000, INITIAL_RESERVE, FEE_BPS).unwrap();
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.protocol_fee_a, math::protocol_fee(swap_amount, FEE_BPS).unwrap());
+    assert_eq!(state.protocol_fee_b, 0);
+}
+
+#[tokio::test]
+async fn single_sided_deposit_error_paths() {
+    let (mut env, pool) = funded_pool().await;
+    let user = trader(&mut env, &pool, 20_000).await;
+    let stray = stray_token_account(&mut env, &user).await;
+    let user_key = user.pubkey();
+
+    let zero = instructions::add_liquidity_single_sided(&user_key, &pool, 0, 0, TokenSide::A, NO_DEADLINE);
+    assert_program_error(env.send(&[zero], &[&user]).await, ErrorCode::ZeroSwapAmount);
+
+    let greedy = instructions::add_liquidity_single_sided(&user_key, &pool, 20_000, 20_000, TokenSide::B, NO_DEADLINE);
+    assert_program_error(env.send(&[greedy], &[&user]).await, ErrorCode::SlippageExceeded);
+
+    let wrong_mint = instructions::add_liquidity_single_sided(&user_key, &pool, 20_000, 0, TokenSide::A, NO_DEADLINE);
+    let wrong_mint = replace_account(wrong_mint, &ata(&user, &pool.token_a_mint), &stray);
+    assert_program_error(env.send(&[wrong_mint], &[&user]).await, ErrorCode::InvalidMint);
+}
+
+#[tokio::test]
+async fn single_sided_deposit_rejects_empty_pool() {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    let user = trader(&mut env, &pool, 20_000).await;
+
+    let deposit = instructions::add_liquidity_single_sided(&user.pubkey(), &pool, 20_000, 0, TokenSide::A, NO_DEADLINE);
+    assert_program_error(env.send(&[deposit], &[&user]).await, ErrorCode::PoolEmpty);
+}
+
+// remove_liquidity_single_sided
+
+#[tokio::test]
+async fn single_sided_withdrawal_pays_one_token() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+
+    let withdrawal = instructions::remove_liquidity_single_sided(&user.pubkey(), &pool, 10_000, 1, TokenSide::B, NO_DEADLINE);
+    env.send(&[withdrawal], &[&user]).await.unwrap();
+
+    // 10_000 B share plus the swapped 10_000 A share, minus price impact and fee (19_871)
+    let received = env.token_balance(&ata(&user, &pool.token_b_mint)).await;
+    assert!(received > 19_800 && received < 20_000, "received {}", received);
+    assert_eq!(env.token_balance(&ata(&user, &pool.lp_mint)).await, 0);
+
+    // The A share sold for B accrues the protocol fee like a plain swap, in token A
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!(state.protocol_fee_a, math::protocol_fee(10_000, FEE_BPS).unwrap());
+    assert_eq!(state.protocol_fee_b, 0);
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.local_reserve_a, env.token_balance(&pool.token_a_account).await - state.protocol_fee_a);
+}
+
+#[tokio::test]
+async fn single_sided_withdrawal_error_paths() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+    let other = liquidity_provider(&mut env, &pool, 10_000).await;
+    let user_key = user.pubkey();
+
+    let zero = instructions::remove_liquidity_single_sided(&user_key, &pool, 0, 0, TokenSide::A, NO_DEADLINE);
+    assert_program_error(env.send(&[zero], &[&user]).await, ErrorCode::ZeroLiquidityBurned);
+
+    let too_much = instructions::remove_liquidity_single_sided(&user_key, &pool, 10_001, 0, TokenSide::A, NO_DEADLINE);
+    assert_program_error(env.send(&[too_much], &[&user]).await, ErrorCode::InsufficientLpTokens);
+
+    let greedy = instructions::remove_liquidity_single_sided(&user_key, &pool, 10_000, 20_000, TokenSide::A, NO_DEADLINE);
+    assert_program_error(env.send(&[greedy], &[&user]).await, ErrorCode::SlippageExceeded);
+
+    // Token account of the other token than `side`
+    let wrong_mint = instructions::remove_liquidity_single_sided(&user_key, &pool, 1_000, 0, TokenSide::A, NO_DEADLINE);
+    let wrong_mint = replace_account(wrong_mint, &ata(&user, &pool.token_a_mint), &ata(&user, &pool.token_b_mint));
+    assert_program_error(env.send(&[wrong_mint], &[&user]).await, ErrorCode::InvalidMint);
+
+    let wrong_owner = instructions::remove_liquidity_single_sided(&user_key, &pool, 1_000, 0, TokenSide::A, NO_DEADLINE);
+    let wrong_owner = replace_account(wrong_owner, &ata(&user, &pool.token_a_mint), &ata(&other, &pool.token_a_mint));
+    assert_program_error(env.send(&[wrong_owner], &[&user]).await, ErrorCode::InvalidOwner);
+}
+
+// bridge_lp_out
+
+#[tokio::test]
+async fn bridge_lp_out_burns_lp_and_moves_it_to_the_remote_supply() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+    let message = Keypair::new();
+
+    let bridge_out = instructions::bridge_lp_out(&user.pubkey(), &pool, &message.pubkey(), 4_000, [1u8; 32], NO_DEADLINE);
+    env.send(&[bridge_out], &[&user, &message]).await.unwrap();
+
+    assert_eq!(env.token_balance(&ata(&user, &pool.lp_mint)).await, 6_000);
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.local_lp_supply, INITIAL_RESERVE + 6_000);
+    assert_eq!(ledger.remote_lp_supply, 4_000);
+    // The reserves stay on Solana
+    assert_eq!(ledger.local_reserve_a, INITIAL_RESERVE + 10_000);
+}
+
+#[tokio::test]
+async fn bridge_lp_out_error_paths() {
+    let (mut env, pool) = funded_pool().await;
+    let user = liquidity_provider(&mut env, &pool, 10_000).await;
+    let user_key = user.pubkey();
+
+    let message = Keypair::new();
+    let zero = instructions::bridge_lp_out(&user_key, &pool, &message.pubkey(), 0, [1u8; 32], NO_DEADLINE);
+    assert_program_error(env.send(&[zero], &[&user, &message]).await, ErrorCode::ZeroLiquidityBurned);
+
+    let too_much = instructions::bridge_lp_out(&user_key, &pool, &message.pubkey(), 10_001, [1u8; 32], NO_DEADLINE);
+    assert_program_error(env.send(&[too_much], &[&user, &message]).await, ErrorCode::InsufficientLpTokens);
+
+    let expired = instructions::bridge_lp_out(&user_key, &pool, &message.pubkey(), 1_000, [1u8; 32], 0);
+    assert_program_error(env.send(&[expired], &[&user, &message]).await, ErrorCode::DeadlineExceeded);
+
+    let wrong_mint = instructions::bridge_lp_out(&user_key, &pool, &message.pubkey(), 1_000, [1u8; 32], NO_DEADLINE);
+    let wrong_mint = replace_account(wrong_mint, &ata(&user, &pool.lp_mint), &ata(&user, &pool.token_a_mint));
+    assert_program_error(env.send(&[wrong_mint], &[&user, &message]).await, ErrorCode::InvalidMint);
+}
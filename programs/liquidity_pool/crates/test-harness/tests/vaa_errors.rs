@@ -0,0 +1,580 @@
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::math;
+use liquidity_pool::payloads::{
+    AddLiquidityCompletionPayload, BridgeOperationCode, LpMigrationPayload, ReserveSnapshotPayload, SwapIntentPayload,
+};
+use liquidity_pool::state::{BridgeRequest, BridgeStatus, Pool, PoolLedger, RemotePoolState};
+use liquidity_pool_client::pda::{self, PoolAddresses};
+use liquidity_pool_client::{core_bridge::GuardianSetData, instructions, vaa::VaaInfo, WORMHOLE_PROGRAM_ID};
+use omnisphere_test_harness::env::DEFAULT_GUARDIAN_COUNT;
+use omnisphere_test_harness::{
+    assert_program_error, replace_account, wormhole, MockGuardians, MockVaa, TestEnv, NO_DEADLINE, SUI_CHAIN_ID,
+    SUI_EMITTER,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{account::AccountSharedData, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program};
+use spl_associated_token_account::get_associated_token_address;
+
+// Inbound VAA processing: posting through the core bridge, guardian set rotation, payload and account
+// checks of the `process_*_vaa` instructions, relayer fees, swap intents and LP migrations.
+
+const POOL_ID: [u8; 32] = [8u8; 32];
+const FEE_BPS: u64 = 30;
+const INITIAL_RESERVE: u64 = 1_000_000;
+
+// Funded pool with the mock Sui pool registered as an unordered emitter
+async fn setup() -> (TestEnv, PoolAddresses) {
+    let mut env = TestEnv::start().await;
+    let pool = env.create_pool(FEE_BPS, POOL_ID).await;
+    env.add_liquidity(&pool, INITIAL_RESERVE, INITIAL_RESERVE).await;
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, false, 0).await;
+    (env, pool)
+}
+
+fn add_payload(recipient: &Pubkey, lp_amount: u64) -> AddLiquidityCompletionPayload {
+    AddLiquidityCompletionPayload {
+        recipient_address: recipient.to_bytes(),
+        lp_amount_to_mint: lp_amount,
+        original_pool_id: POOL_ID,
+        relayer_fee: 0,
+        target_token_account: None,
+        receiver_program: None,
+        amount_a_deposited: 1_000,
+        amount_b_deposited: 1_000,
+    }
+}
+
+fn add_completion(sequence: u64, payload: &AddLiquidityCompletionPayload) -> MockVaa {
+    MockVaa::new(sequence, BridgeOperationCode::AddLiquidityCompletion, payload)
+}
+
+fn snapshot(sequence: u64, lp_supply: u64, timestamp: i64) -> MockVaa {
+    MockVaa::new(
+        sequence,
+        BridgeOperationCode::ReserveSnapshot,
+        &ReserveSnapshotPayload {
+            original_pool_id: POOL_ID,
+            reserve_a: INITIAL_RESERVE,
+            reserve_b: INITIAL_RESERVE,
+            lp_supply,
+            timestamp,
+        },
+    )
+}
+
+fn swap_payload(recipient: &Pubkey, amount_in: u64, min_amount_out: u64) -> SwapIntentPayload {
+    SwapIntentPayload {
+        recipient_address: recipient.to_bytes(),
+        refund_address: [6u8; 32],
+        original_pool_id: POOL_ID,
+        amount_in,
+        min_amount_out,
+        deadline: NO_DEADLINE,
+        a_to_b: true,
+        relayer_fee: 0,
+        target_token_account: None,
+    }
+}
+
+fn swap_intent(sequence: u64, recipient: &Pubkey, amount_in: u64, min_amount_out: u64) -> MockVaa {
+    MockVaa::new(sequence, BridgeOperationCode::SwapIntent, &swap_payload(recipient, amount_in, min_amount_out))
+}
+
+fn lp_migration(sequence: u64, recipient: &Pubkey, lp_amount: u64) -> MockVaa {
+    MockVaa::new(
+        sequence,
+        BridgeOperationCode::LpMigration,
+        &LpMigrationPayload {
+            sender_address: [6u8; 32],
+            recipient_address: recipient.to_bytes(),
+            original_pool_id: POOL_ID,
+            lp_amount,
+            reserve_a_share: 1_000,
+            reserve_b_share: 1_000,
+        },
+    )
+}
+
+// Core bridge posting
+
+// The VAA with only its first `count` guardian signatures
+fn keep_signatures(signed: &[u8], count: usize) -> Vec<u8> {
+    let body_offset = 6 + 66 * signed[5] as usize;
+    let mut kept = signed[..5].to_vec();
+    kept.push(count as u8);
+    kept.extend_from_slice(&signed[6..6 + 66 * count]);
+    kept.extend_from_slice(&signed[body_offset..]);
+    kept
+}
+
+async fn assert_not_posted(env: &mut TestEnv, result: Result<VaaInfo, BanksClientError>, signed: &[u8]) {
+    assert!(result.is_err(), "VAA was posted");
+    let hash = VaaInfo::parse(signed).unwrap().hash;
+    assert!(!env.account_exists(&pda::posted_vaa(&hash)).await);
+}
+
+#[tokio::test]
+async fn tampered_vaa_body_is_not_posted() {
+    let (mut env, _) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let mut signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    *signed.last_mut().unwrap() ^= 1;
+
+    let result = env.post_vaa(&signed).await;
+    assert_not_posted(&mut env, result, &signed).await;
+}
+
+#[tokio::test]
+async fn vaa_signed_by_impostor_guardians_is_not_posted() {
+    let (mut env, _) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    // Other keys claiming to be guardian set 0
+    let mut impostors = MockGuardians::new(1, DEFAULT_GUARDIAN_COUNT);
+    impostors.set_index = 0;
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&impostors);
+
+    let result = env.post_vaa(&signed).await;
+    assert_not_posted(&mut env, result, &signed).await;
+}
+
+#[tokio::test]
+async fn vaa_needs_a_guardian_quorum() {
+    let (mut env, _) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let quorum = DEFAULT_GUARDIAN_COUNT as usize * 2 / 3 + 1;
+
+    let below_quorum = keep_signatures(&signed, quorum - 1);
+    let result = env.post_vaa(&below_quorum).await;
+    assert_not_posted(&mut env, result, &below_quorum).await;
+
+    let info = env.post_vaa(&keep_signatures(&signed, quorum)).await.expect("post VAA");
+    assert!(env.account_exists(&pda::posted_vaa(&info.hash)).await);
+}
+
+#[tokio::test]
+async fn vaa_from_an_unknown_guardian_set_is_not_posted() {
+    let (mut env, _) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let next = MockGuardians::new(1, DEFAULT_GUARDIAN_COUNT);
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&next);
+
+    // Guardian set 1 was never created on chain
+    let guardian_set = GuardianSetData { index: 1, keys: next.addresses(), creation_time: 0, expiration_time: 0 };
+    let result = env.post_vaa_with_guardian_set(&signed, &guardian_set).await;
+    assert_not_posted(&mut env, result, &signed).await;
+}
+
+// Guardian sets
+
+#[tokio::test]
+async fn previous_guardian_set_is_accepted_until_it_expires() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let previous = env.rotate_guardian_set(MockGuardians::new(1, DEFAULT_GUARDIAN_COUNT), u32::MAX);
+
+    // Each BridgeRequest records the set that signed its VAA
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&previous);
+    env.process_signed_vaa(&signed, &pool).await.unwrap();
+    let hash = VaaInfo::parse(&signed).unwrap().hash;
+    let request: BridgeRequest = env.account(&pda::bridge_request(&hash)).await;
+    assert_eq!(request.guardian_set_index, 0);
+
+    let signed = add_completion(1, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    env.process_signed_vaa(&signed, &pool).await.unwrap();
+    let hash = VaaInfo::parse(&signed).unwrap().hash;
+    let request: BridgeRequest = env.account(&pda::bridge_request(&hash)).await;
+    assert_eq!(request.guardian_set_index, 1);
+}
+
+#[tokio::test]
+async fn expired_guardian_set_can_no_longer_post() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let posted = env.post_vaa(&signed).await.expect("post VAA");
+
+    let previous = env.rotate_guardian_set(MockGuardians::new(1, DEFAULT_GUARDIAN_COUNT), 1);
+    let signed = add_completion(1, &add_payload(&recipient, 1_000)).signed_by(&previous);
+    let result = env.post_vaa(&signed).await;
+    assert_not_posted(&mut env, result, &signed).await;
+
+    // A VAA posted before the set expired is still processed
+    env.process_posted_vaa(&posted, &pool).await.unwrap();
+    let request: BridgeRequest = env.account(&pda::bridge_request(&posted.hash)).await;
+    assert_eq!(request.guardian_set_index, 0);
+}
+
+// Shared VAA checks
+
+#[tokio::test]
+async fn vaa_hash_must_match_the_posted_vaa() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    let mut forged = info.clone();
+    forged.hash = [1u8; 32];
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &forged, &pool).unwrap();
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::VaaHashMismatch);
+}
+
+#[tokio::test]
+async fn posted_vaa_not_owned_by_the_core_bridge_is_rejected() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = VaaInfo::parse(&signed).unwrap();
+
+    // Well-formed PostedVAA data at the expected address, but not written by the core bridge
+    let mut forged = wormhole::posted_vaa_account(&signed);
+    forged.owner = system_program::ID;
+    let posted_vaa = pda::posted_vaa(&info.hash);
+    env.context.set_account(&posted_vaa, &AccountSharedData::from(forged));
+
+    // The signature set is never read: the posted VAA is rejected first
+    let payer = env.payer();
+    let instruction = instructions::process_vaa(&payer, &posted_vaa, &Pubkey::default(), &info, &pool).unwrap();
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidPostedVaa);
+}
+
+#[tokio::test]
+async fn posted_vaa_must_be_at_the_address_of_its_hash() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = VaaInfo::parse(&signed).unwrap();
+
+    // Core bridge owned account at another address
+    let elsewhere = Keypair::new().pubkey();
+    env.context.set_account(&elsewhere, &AccountSharedData::from(wormhole::posted_vaa_account(&signed)));
+
+    let payer = env.payer();
+    let instruction = instructions::process_vaa(&payer, &elsewhere, &Pubkey::default(), &info, &pool).unwrap();
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidPostedVaa);
+}
+
+#[tokio::test]
+async fn signature_set_must_be_the_one_of_the_posted_vaa() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let other = add_completion(1, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let other = env.post_vaa(&other).await.expect("post VAA");
+
+    let other_signature_set = env.signature_set(&other.hash).await;
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &other_signature_set, &info, &pool).unwrap();
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidSignatureSet);
+}
+
+#[tokio::test]
+async fn vaa_must_match_the_instruction_operation() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    let payer = env.payer();
+    let instruction =
+        instructions::process_reserve_snapshot_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool);
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidBridgeOperation);
+}
+
+#[tokio::test]
+async fn truncated_payload_is_rejected() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let payload = add_payload(&recipient, 1_000);
+    let mut vaa = add_completion(0, &payload);
+    vaa.payload.truncate(20);
+    let signed = vaa.signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    let payer = env.payer();
+    let posted_vaa = pda::posted_vaa(&info.hash);
+    let instruction =
+        instructions::process_add_liquidity_vaa(&payer, &posted_vaa, &signature_set, &info, &pool, &payload);
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidVaaPayload);
+}
+
+#[tokio::test]
+async fn sequence_tracker_must_belong_to_the_emitter() {
+    let (mut env, pool) = setup().await;
+    env.register_emitter(SUI_CHAIN_ID, [6u8; 32], false, 0).await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool).unwrap();
+    let instruction = replace_account(
+        instruction,
+        &pda::sequence_tracker(SUI_CHAIN_ID, &SUI_EMITTER),
+        &pda::sequence_tracker(SUI_CHAIN_ID, &[6u8; 32]),
+    );
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidSequenceTracker);
+}
+
+// Delivery checks (add liquidity completion)
+
+#[tokio::test]
+async fn payload_pool_id_must_match_the_pool() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.original_pool_id = [9u8; 32];
+    assert_program_error(env.process_vaa(&add_completion(0, &payload), &pool).await, ErrorCode::PoolIdMismatch);
+}
+
+#[tokio::test]
+async fn recipient_account_must_match_the_payload() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool).unwrap();
+    let instruction = replace_account(instruction, &recipient, &Keypair::new().pubkey());
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::RecipientMismatch);
+}
+
+#[tokio::test]
+async fn relayer_fee_is_paid_from_the_minted_lp() {
+    let (mut env, pool) = setup().await;
+    let payer = env.payer();
+    let relayer_lp = env.create_token_account(&payer, &pool.lp_mint).await;
+    let relayer_lp_before = env.token_balance(&relayer_lp).await;
+    let recipient = Keypair::new().pubkey();
+
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.relayer_fee = 100;
+    env.process_vaa(&add_completion(0, &payload), &pool).await.unwrap();
+
+    assert_eq!(env.token_balance(&get_associated_token_address(&recipient, &pool.lp_mint)).await, 900);
+    assert_eq!(env.token_balance(&relayer_lp).await, relayer_lp_before + 100);
+}
+
+#[tokio::test]
+async fn relayer_fee_error_paths() {
+    let (mut env, pool) = setup().await;
+    let payer = env.payer();
+    let relayer_lp = env.create_token_account(&payer, &pool.lp_mint).await;
+    let recipient = Keypair::new().pubkey();
+
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.relayer_fee = 1_001;
+    assert_program_error(env.process_vaa(&add_completion(0, &payload), &pool).await, ErrorCode::RelayerFeeTooHigh);
+
+    // The optional relayer account is left out (program ID in its slot)
+    payload.relayer_fee = 100;
+    let signed = add_completion(1, &payload).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool).unwrap();
+    let instruction = replace_account(instruction, &relayer_lp, &liquidity_pool::ID);
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::MissingRelayerFeeAccount);
+}
+
+#[tokio::test]
+async fn delivery_account_must_belong_to_the_recipient() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    // Default delivery: only the recipient's ATA is accepted
+    let signed = add_completion(0, &add_payload(&recipient, 1_000)).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool).unwrap();
+    let instruction = replace_account(
+        instruction,
+        &get_associated_token_address(&recipient, &pool.lp_mint),
+        &get_associated_token_address(&payer, &pool.lp_mint),
+    );
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::InvalidRecipientTokenAccount);
+
+    // Target account from the payload owned by someone else
+    let foreign_account = env.create_token_account(&Keypair::new().pubkey(), &pool.lp_mint).await;
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.target_token_account = Some(foreign_account.to_bytes());
+    assert_program_error(
+        env.process_vaa(&add_completion(1, &payload), &pool).await,
+        ErrorCode::InvalidRecipientTokenAccount,
+    );
+}
+
+#[tokio::test]
+async fn receiver_program_must_match_the_payload() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    let mut payload = add_payload(&recipient, 1_000);
+    payload.receiver_program = Some(WORMHOLE_PROGRAM_ID.to_bytes());
+    let signed = add_completion(0, &payload).signed_by(&env.guardians);
+    let info = env.post_vaa(&signed).await.expect("post VAA");
+    let signature_set = env.signature_set(&info.hash).await;
+
+    // The optional receiver program is left out (program ID in its slot)
+    let payer = env.payer();
+    let instruction =
+        instructions::process_vaa(&payer, &pda::posted_vaa(&info.hash), &signature_set, &info, &pool).unwrap();
+    let instruction = replace_account(instruction, &WORMHOLE_PROGRAM_ID, &liquidity_pool::ID);
+    assert_program_error(env.send(&[instruction], &[]).await, ErrorCode::ReceiverProgramMismatch);
+}
+
+// Reserve snapshots
+
+#[tokio::test]
+async fn stale_reserve_snapshot_is_rejected() {
+    let (mut env, pool) = setup().await;
+
+    env.process_vaa(&snapshot(0, 500_000, 10), &pool).await.unwrap();
+    assert_program_error(env.process_vaa(&snapshot(1, 400_000, 5), &pool).await, ErrorCode::StaleReserveSnapshot);
+
+    let remote: RemotePoolState = env.account(&pda::remote_pool_state(&POOL_ID)).await;
+    assert_eq!(remote.lp_supply, 500_000);
+}
+
+#[tokio::test]
+async fn reserve_snapshot_leaves_the_ledger_alone() {
+    let (mut env, pool) = setup().await;
+    env.process_vaa(&snapshot(0, 500_000, 10), &pool).await.unwrap();
+
+    let remote: RemotePoolState = env.account(&pda::remote_pool_state(&POOL_ID)).await;
+    assert_eq!((remote.reserve_a, remote.lp_supply), (INITIAL_RESERVE, 500_000));
+    // Pricing input only: the ledger tracks Sui through the bridged deposits and withdrawals
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!((ledger.remote_reserve_a, ledger.remote_reserve_b, ledger.remote_lp_supply), (0, 0, 0));
+}
+
+// Swap intents
+
+#[tokio::test]
+async fn swap_intent_delivers_curve_output_and_accrues_protocol_fee() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    let expected_out = math::swap_output(10_000, INITIAL_RESERVE, INITIAL_RESERVE, FEE_BPS).unwrap();
+    let protocol_fee = math::protocol_fee(10_000, FEE_BPS).unwrap();
+
+    env.process_vaa(&swap_intent(0, &recipient, 10_000, expected_out), &pool).await.unwrap();
+
+    assert_eq!(env.token_balance(&get_associated_token_address(&recipient, &pool.token_b_mint)).await, expected_out);
+    let state: Pool = env.account(&pool.pool).await;
+    assert_eq!((state.protocol_fee_a, state.protocol_fee_b), (protocol_fee, 0));
+    // The input stays on Sui; the protocol fee is set aside in the Solana vault of token A
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.remote_reserve_a, 10_000);
+    assert_eq!(ledger.local_reserve_a, INITIAL_RESERVE - protocol_fee);
+    assert_eq!(ledger.local_reserve_a, env.token_balance(&pool.token_a_account).await - state.protocol_fee_a);
+    assert_eq!(ledger.local_reserve_b, INITIAL_RESERVE - expected_out);
+    assert_eq!(ledger.total_reserve_a().unwrap(), INITIAL_RESERVE + 10_000 - protocol_fee);
+}
+
+#[tokio::test]
+async fn swap_intent_below_minimum_is_refunded() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    let vaa = swap_intent(0, &recipient, 10_000, 10_000);
+    env.process_vaa(&vaa, &pool).await.unwrap();
+
+    let recipient_b = get_associated_token_address(&recipient, &pool.token_b_mint);
+    assert!(!env.account_exists(&recipient_b).await);
+    assert_eq!(env.token_balance(&pool.token_b_account).await, INITIAL_RESERVE);
+
+    let signed = vaa.signed_by(&env.guardians);
+    let hash = env.post_vaa(&signed).await.expect("post VAA").hash;
+    let request: BridgeRequest = env.account(&pda::bridge_request(&hash)).await;
+    assert!(request.status == BridgeStatus::Failed);
+}
+
+#[tokio::test]
+async fn swap_intent_not_covering_the_relayer_fee_is_refunded() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    env.register_emitter(SUI_CHAIN_ID, SUI_EMITTER, true, 0).await;
+
+    let mut payload = swap_payload(&recipient, 10_000, 0);
+    payload.relayer_fee = 10_000;
+    env.process_vaa(&MockVaa::new(0, BridgeOperationCode::SwapIntent, &payload), &pool).await.unwrap();
+    assert_eq!(env.token_balance(&pool.token_b_account).await, INITIAL_RESERVE);
+
+    // The ordered emitter isn't blocked: the next sequence is processed
+    let expected_out = math::swap_output(10_000, INITIAL_RESERVE, INITIAL_RESERVE, FEE_BPS).unwrap();
+    env.process_vaa(&swap_intent(1, &recipient, 10_000, expected_out), &pool).await.unwrap();
+    assert_eq!(env.token_balance(&get_associated_token_address(&recipient, &pool.token_b_mint)).await, expected_out);
+}
+
+#[tokio::test]
+async fn swap_intent_past_its_deadline_is_refunded() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    // Would execute at any price, but the deadline passed before it was relayed
+    let mut payload = swap_payload(&recipient, 10_000, 0);
+    payload.deadline = 1;
+    let vaa = MockVaa::new(0, BridgeOperationCode::SwapIntent, &payload);
+    env.process_vaa(&vaa, &pool).await.unwrap();
+
+    assert!(!env.account_exists(&get_associated_token_address(&recipient, &pool.token_b_mint)).await);
+    assert_eq!(env.token_balance(&pool.token_b_account).await, INITIAL_RESERVE);
+    let hash = env.post_vaa(&vaa.signed_by(&env.guardians)).await.expect("post VAA").hash;
+    let request: BridgeRequest = env.account(&pda::bridge_request(&hash)).await;
+    assert!(request.status == BridgeStatus::Failed);
+}
+
+#[tokio::test]
+async fn swap_intent_rejects_zero_amount() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    assert_program_error(env.process_vaa(&swap_intent(0, &recipient, 0, 0), &pool).await, ErrorCode::ZeroSwapAmount);
+}
+
+// LP migrations
+
+#[tokio::test]
+async fn lp_migration_mints_lp_moved_from_sui() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+    // LP held on Sui: moved there by the payer
+    let payer = env.payer();
+    let message = Keypair::new();
+    let bridge_out = instructions::bridge_lp_out(&payer, &pool, &message.pubkey(), 500_000, [1u8; 32], NO_DEADLINE);
+    env.send(&[bridge_out], &[&message]).await.unwrap();
+
+    env.process_vaa(&lp_migration(0, &recipient, 1_000), &pool).await.unwrap();
+
+    assert_eq!(env.token_balance(&get_associated_token_address(&recipient, &pool.lp_mint)).await, 1_000);
+    let ledger: PoolLedger = env.account(&pool.ledger).await;
+    assert_eq!(ledger.remote_lp_supply, 499_000);
+    assert_eq!(ledger.local_lp_supply, INITIAL_RESERVE - 500_000 + 1_000);
+}
+
+#[tokio::test]
+async fn lp_migration_rejects_zero_amount() {
+    let (mut env, pool) = setup().await;
+    let recipient = Keypair::new().pubkey();
+
+    assert_program_error(
+        env.process_vaa(&lp_migration(0, &recipient, 0), &pool).await,
+        ErrorCode::ZeroLiquidityMinted,
+    );
+}
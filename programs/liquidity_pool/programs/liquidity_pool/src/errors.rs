@@ -61,8 +61,59 @@ pub enum ErrorCode {
     InvalidEmitterChain, // Placeholder for future check
 
     #[msg("Invalid emitter address in VAA.")]
-    InvalidEmitterAddress, // Placeholder for future check
+    InvalidEmitterAddress, // Emitter is not registered (no sequence tracker)
 
     #[msg("Received VAA with an unsupported payload kind.")]
     UnsupportedPayloadKind,
+
+    #[msg("Relayer fee exceeds the amount delivered by the bridge operation.")]
+    RelayerFeeTooHigh,
+
+    #[msg("Relayer fee token account is required when the payload carries a relayer fee.")]
+    MissingRelayerFeeAccount,
+
+    #[msg("Recipient token account does not match the VAA payload or is not owned by the recipient.")]
+    InvalidRecipientTokenAccount,
+
+    #[msg("Receiver program account does not match the VAA payload.")]
+    ReceiverProgramMismatch,
+
+    #[msg("VAA sequence is out of order for an emitter that requires in-order processing.")]
+    SequenceOutOfOrder,
+
+    #[msg("Sequence tracker account does not match the VAA emitter.")]
+    InvalidSequenceTracker,
+
+    #[msg("VAA hash argument does not match the posted VAA.")]
+    VaaHashMismatch,
+
+    #[msg("Swap amount must be greater than zero.")]
+    ZeroSwapAmount,
+
+    #[msg("The Solana pool does not hold enough reserves for this withdrawal.")]
+    InsufficientLocalReserves,
+
+    #[msg("Reserve snapshot is older than the last one processed.")]
+    StaleReserveSnapshot,
+
+    #[msg("Initial deposit is too small to cover the locked minimum liquidity.")]
+    InsufficientInitialLiquidity,
+
+    #[msg("Division by zero.")]
+    DivisionByZero,
+
+    #[msg("Pool has outstanding LP tokens but an empty reserve; the deposit ratio is undefined.")]
+    PoolDrained,
+
+    #[msg("Transaction deadline has passed.")]
+    DeadlineExceeded,
+
+    #[msg("Fee must be lower than 10000 basis points.")]
+    InvalidFee,
+
+    #[msg("Posted VAA account is not a PostedVAA account of the Wormhole core bridge.")]
+    InvalidPostedVaa,
+
+    #[msg("Signature set account is not the core bridge signature set of the posted VAA.")]
+    InvalidSignatureSet,
 }
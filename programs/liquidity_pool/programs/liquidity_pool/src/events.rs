@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeStatus, ConsistencyLevel, MAX_OUTBOUND_OPERATIONS};
+
+// Events are emitted with emit_cpi! (self-CPI into the event authority) so they are recorded
+// in the instruction data of the transaction and survive log truncation.
+
+#[event]
+pub struct PoolCreated {
+    pub pool: Pubkey,
+    pub pool_id: [u8; 32],
+    pub creator: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub fee_percentage: u64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub amount_a_desired: u64,      // Amounts offered by the user
+    pub amount_b_desired: u64,
+    pub amount_a: u64,              // Amounts actually deposited
+    pub amount_b: u64,
+    pub lp_tokens_minted: u64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub amount_a: u64,              // Amounts paid out to the user
+    pub amount_b: u64,
+    pub lp_tokens_burned: u64,
+}
+
+#[event]
+pub struct Swap {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub a_to_b: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub protocol_fee: u64,          // Part of the fee accrued to the protocol (in the input token)
+}
+
+#[event]
+pub struct FeesCollected {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
+
+#[event]
+pub struct VaaProcessed {
+    pub pool: Pubkey,
+    pub vaa_hash: [u8; 32],
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub operation_code: u8,
+    pub status: BridgeStatus,       // Failed when the operation was refunded instead of executed
+}
+
+#[event]
+pub struct StatusChanged {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub old_status: u8,
+    pub new_status: u8,
+}
+
+#[event]
+pub struct FeeChanged {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub old_fee: u64,               // Basis points
+    pub new_fee: u64,
+}
+
+#[event]
+pub struct LpBridgedOut {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub sui_recipient: [u8; 32],
+    pub lp_amount: u64,
+    pub reserve_a_share: u64,       // Share of the global reserves recorded in the message
+    pub reserve_b_share: u64,
+}
+
+#[event]
+pub struct EmitterOrderingSet {
+    pub admin: Pubkey,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub ordered: bool,
+    pub next_expected_sequence: u64,
+}
+
+#[event]
+pub struct WormholeSettingsUpdated {
+    pub admin: Pubkey,
+    pub batch_nonce: u32,           // Settings after the update
+    pub consistency_levels: [ConsistencyLevel; MAX_OUTBOUND_OPERATIONS],
+}
+
+#[event]
+pub struct RelayerSettingsUpdated {
+    pub admin: Pubkey,
+    pub old_rent_reimbursement_lamports: u64,
+    pub new_rent_reimbursement_lamports: u64,
+}
+
+#[event]
+pub struct DriftThresholdUpdated {
+    pub admin: Pubkey,
+    pub old_max_price_drift_bps: u64,
+    pub new_max_price_drift_bps: u64,
+}
@@ -3,9 +3,20 @@ use anchor_spl::{
     associated_token::AssociatedToken, // Import AssociatedToken
     token::{self, Mint, Token, TokenAccount, Transfer, MintTo}
 };
-use crate::state::Pool;
+use crate::state::{Pool, PoolLedger};
 use crate::errors::ErrorCode; // Assuming you'll create an errors.rs file later
+use crate::math;
+use crate::events::LiquidityAdded;
 
+// Outcome of add_liquidity, returned to the caller as Solana return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityOutcome {
+    pub amount_a: u64,          // Token A actually deposited
+    pub amount_b: u64,          // Token B actually deposited
+    pub lp_tokens_minted: u64,  // LP tokens minted to the user
+}
+
+    #[event_cpi]
     #[derive(Accounts)]
     pub struct AddLiquidity<'info> {
         // User adding liquidity
@@ -28,6 +39,14 @@ use crate::errors::ErrorCode; // Assuming you'll create an errors.rs file later
         )]
         pub pool_authority: AccountInfo<'info>,
 
+        // Global LP ledger of the pool
+        #[account(
+            mut,
+            seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+            bump = ledger.bump
+        )]
+        pub ledger: Account<'info, PoolLedger>,
+
         // Pool's token accounts
         #[account(
             mut,
@@ -69,6 +88,14 @@ use crate::errors::ErrorCode; // Assuming you'll create an errors.rs file later
         )]
         pub lp_mint: Account<'info, Mint>,
 
+        // Dead LP token account receiving MINIMUM_LIQUIDITY on the first deposit
+        #[account(
+            mut,
+            seeds = [b"locked_lp".as_ref(), pool.key().as_ref()],
+            bump
+        )]
+        pub locked_lp_token_account: Account<'info, TokenAccount>,
+
         // User's LP token account (might need to be created if it doesn't exist)
         #[account(
             init_if_needed, // Creates the account if it doesn't exist
@@ -92,53 +119,32 @@ use crate::errors::ErrorCode; // Assuming you'll create an errors.rs file later
         amount_b_desired: u64,
         amount_a_min: u64, // Minimum amount of token A user is willing to deposit
         amount_b_min: u64, // Minimum amount of token B user is willing to deposit
-    ) -> Result<()> {
+        deadline: i64,     // Unix timestamp after which the transaction is rejected
+    ) -> Result<AddLiquidityOutcome> {
         msg!("Adding liquidity...");
         let pool = &mut ctx.accounts.pool;
         let clock = Clock::get()?;
+        check_deadline(&clock, deadline)?;
 
         // Check if pool is active
         require!(pool.status == 0, ErrorCode::PoolPaused); // Assuming 0 is Active
 
-        let reserve_a = ctx.accounts.token_a_account.amount;
-        let reserve_b = ctx.accounts.token_b_account.amount;
-        let lp_supply = ctx.accounts.lp_mint.supply;
-
-        let (amount_a_optimal, amount_b_optimal) = if lp_supply == 0 {
-            // First liquidity provider
-            (amount_a_desired, amount_b_desired)
-        } else {
-            // Calculate optimal amounts based on current reserves
-            let amount_b_optimal_calc = amount_a_desired.checked_mul(reserve_b).unwrap().checked_div(reserve_a).unwrap();
-            if amount_b_optimal_calc <= amount_b_desired {
-                (amount_a_desired, amount_b_optimal_calc)
-            } else {
-                let amount_a_optimal_calc = amount_b_desired.checked_mul(reserve_a).unwrap().checked_div(reserve_b).unwrap();
-                (amount_a_optimal_calc, amount_b_desired)
-            }
-        };
+        // Quote against the combined Solana + Sui reserves and LP supply
+        let ledger = &mut ctx.accounts.ledger;
+        let reserve_a = ledger.total_reserve_a()?;
+        let reserve_b = ledger.total_reserve_b()?;
+        let lp_supply = ledger.total_lp_supply()?;
+
+        // Optimal amounts and LP to mint (fails with PoolDrained if LP exists but a reserve is empty)
+        let quote = math::quote_add_liquidity(amount_a_desired, amount_b_desired, reserve_a, reserve_b, lp_supply)?;
+        let amount_a_optimal = quote.amount_a;
+        let amount_b_optimal = quote.amount_b;
+        let liquidity_minted = quote.liquidity;
 
         // Check against minimum amounts
         require!(amount_a_optimal >= amount_a_min, ErrorCode::SlippageExceeded);
         require!(amount_b_optimal >= amount_b_min, ErrorCode::SlippageExceeded);
 
-        // Calculate LP tokens to mint
-        let liquidity_minted = if lp_supply == 0 {
-            // Use geometric mean for the first provider, or a simpler approach like 100 units
-            // Let's use a simpler fixed amount for the first liquidity to avoid sqrt complexity or large numbers
-            // Or base it on one of the amounts, e.g., amount_a_optimal
-            amount_a_optimal // Simplification: mint LP tokens equal to the amount of token A deposited initially
-                             // A more common approach is sqrt(amount_a * amount_b), but requires U128 or similar
-        } else {
-            // Calculate based on the ratio of deposit to reserves
-            std::cmp::min(
-                amount_a_optimal.checked_mul(lp_supply).unwrap().checked_div(reserve_a).unwrap(),
-                amount_b_optimal.checked_mul(lp_supply).unwrap().checked_div(reserve_b).unwrap(),
-            )
-        };
-
-        require!(liquidity_minted > 0, ErrorCode::ZeroLiquidityMinted);
-
         // Transfer tokens from user to pool
         transfer_tokens(
             ctx.accounts.token_program.to_account_info(),
@@ -167,12 +173,48 @@ use crate::errors::ErrorCode; // Assuming you'll create an errors.rs file later
             ctx.bumps.pool_authority, // Access bump directly
         )?;
 
+        // Lock MINIMUM_LIQUIDITY in the dead account on the first deposit
+        if quote.locked_liquidity > 0 {
+            mint_lp_tokens(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.lp_mint.to_account_info(),
+                ctx.accounts.locked_lp_token_account.to_account_info(),
+                ctx.accounts.pool_authority.to_account_info(),
+                pool.key(),
+                quote.locked_liquidity,
+                ctx.bumps.pool_authority,
+            )?;
+        }
+        let total_minted = math::checked_add(liquidity_minted, quote.locked_liquidity)?;
+
         // Update pool state (reload amounts after transfer)
-        pool.total_liquidity = pool.total_liquidity.checked_add(liquidity_minted).unwrap();
+        pool.total_liquidity = math::checked_add(pool.total_liquidity, total_minted)?;
         pool.last_updated_at = clock.unix_timestamp;
+        ledger.add_local(amount_a_optimal, amount_b_optimal, total_minted)?;
 
         msg!("Liquidity added: A={}, B={}, LP={}", amount_a_optimal, amount_b_optimal, liquidity_minted);
 
+        // Only the optimal amounts were transferred; report them next to what the user offered
+        emit_cpi!(LiquidityAdded {
+            pool: pool.key(),
+            user: ctx.accounts.user.key(),
+            amount_a_desired,
+            amount_b_desired,
+            amount_a: amount_a_optimal,
+            amount_b: amount_b_optimal,
+            lp_tokens_minted: liquidity_minted,
+        });
+
+        Ok(AddLiquidityOutcome {
+            amount_a: amount_a_optimal,
+            amount_b: amount_b_optimal,
+            lp_tokens_minted: liquidity_minted,
+        })
+    }
+
+    // Helper function rejecting transactions that land after the user's deadline
+    pub fn check_deadline(clock: &Clock, deadline: i64) -> Result<()> {
+        require!(clock.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
         Ok(())
     }
 
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{Pool, PoolLedger, TokenSide};
+use crate::errors::ErrorCode;
+use crate::math;
+use crate::events::{self, LiquidityAdded};
+use crate::instructions::add_liquidity::{transfer_tokens, mint_lp_tokens, check_deadline};
+use crate::instructions::remove_liquidity::transfer_pool_tokens;
+use crate::instructions::swap::settle_local_swap_leg;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquiditySingleSided<'info> {
+    // User adding liquidity
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool state account
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Pool authority PDA
+    /// CHECK: Authority PDA, seeds checked below. Used as signer.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    // Pool's token accounts
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    // User's token accounts (the other side receives any swap output left after the deposit)
+    #[account(
+        mut,
+        constraint = user_token_a.mint == pool.token_a_mint @ ErrorCode::InvalidMint,
+        constraint = user_token_a.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_b.mint == pool.token_b_mint @ ErrorCode::InvalidMint,
+        constraint = user_token_b.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    // LP token mint
+    #[account(
+        mut,
+        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
+        bump = pool.lp_mint_bump,
+        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    // User's LP token account
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_token_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+}
+
+// Handler function depositing a single token: swaps the optimal part of `amount_in` into the
+// other token through the curve (paying the pool fee), then deposits both legs for LP tokens
+pub fn handler(
+    ctx: Context<AddLiquiditySingleSided>,
+    amount_in: u64,      // Amount of the deposited token
+    min_lp_out: u64,     // Minimum LP tokens the user accepts
+    side: TokenSide,     // Token being deposited
+    deadline: i64,       // Unix timestamp after which the transaction is rejected
+) -> Result<()> {
+    msg!("Adding single-sided liquidity...");
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+    check_deadline(&clock, deadline)?;
+
+    require!(pool.status == 0, ErrorCode::PoolPaused);
+    require!(amount_in > 0, ErrorCode::ZeroSwapAmount);
+
+    // The swap leg is priced like `swap`, on the Solana vaults it is paid from; the deposit leg on the
+    // combined Solana + Sui reserves, the basis LP tokens are minted on
+    let ledger = &mut ctx.accounts.ledger;
+    let a_to_b = side == TokenSide::A;
+    let zap = math::quote_add_liquidity_single_sided(
+        amount_in,
+        a_to_b,
+        (ledger.total_reserve_a()?, ledger.total_reserve_b()?),
+        ledger.total_lp_supply()?,
+        pool.lp_reserves(ctx.accounts.token_a_account.amount, ctx.accounts.token_b_account.amount),
+        pool.fee_percentage,
+    )?;
+    let quote = zap.deposit;
+    require!(quote.liquidity >= min_lp_out, ErrorCode::SlippageExceeded);
+    let (swap_amount, swap_out, protocol_fee) = (zap.swap_amount, zap.swap_out, zap.protocol_fee);
+    let (amount_pulled, output_left) = (zap.amount_pulled, zap.output_returned);
+
+    let swap = math::SwapQuote { amount_out: swap_out, protocol_fee };
+    settle_local_swap_leg(pool, ledger, swap_amount, a_to_b, &swap)?;
+
+    // The deposited token goes into its vault; the unused swap output comes back from the other one
+    let (user_source, pool_destination, pool_source, user_destination) = match side {
+        TokenSide::A => (&ctx.accounts.user_token_a, &ctx.accounts.token_a_account, &ctx.accounts.token_b_account, &ctx.accounts.user_token_b),
+        TokenSide::B => (&ctx.accounts.user_token_b, &ctx.accounts.token_b_account, &ctx.accounts.token_a_account, &ctx.accounts.user_token_a),
+    };
+
+    transfer_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        user_source.to_account_info(),
+        pool_destination.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount_pulled,
+    )?;
+
+    if output_left > 0 {
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            pool_source.to_account_info(),
+            user_destination.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            pool.key(),
+            output_left,
+            ctx.bumps.pool_authority,
+        )?;
+    }
+
+    mint_lp_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.lp_mint.to_account_info(),
+        ctx.accounts.user_lp_token_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        pool.key(),
+        quote.liquidity,
+        ctx.bumps.pool_authority,
+    )?;
+
+    pool.total_liquidity = math::checked_add(pool.total_liquidity, quote.liquidity)?;
+    pool.last_updated_at = clock.unix_timestamp;
+    ledger.add_local(quote.amount_a, quote.amount_b, quote.liquidity)?;
+
+    msg!(
+        "Single-sided liquidity added: in={}, swapped={}, A={}, B={}, LP={}",
+        amount_pulled, swap_amount, quote.amount_a, quote.amount_b, quote.liquidity
+    );
+
+    emit_cpi!(events::Swap {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        a_to_b,
+        amount_in: swap_amount,
+        amount_out: swap_out,
+        protocol_fee,
+    });
+
+    let (amount_a_desired, amount_b_desired) = match side {
+        TokenSide::A => (amount_in, 0),
+        TokenSide::B => (0, amount_in),
+    };
+    emit_cpi!(LiquidityAdded {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        amount_a_desired,
+        amount_b_desired,
+        amount_a: quote.amount_a,
+        amount_b: quote.amount_b,
+        lp_tokens_minted: quote.liquidity,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{Pool, PoolLedger, Config, OutboundOperation};
+use crate::errors::ErrorCode;
+use crate::math;
+use crate::events::LpBridgedOut;
+use crate::payloads::LpMigrationPayload;
+use crate::outbound::{post_outbound_message, MessageSigner, OutboundMessageAccounts, EMITTER_SEED};
+use crate::instructions::remove_liquidity::burn_lp_tokens;
+use crate::instructions::add_liquidity::check_deadline;
+use wormhole_anchor_sdk::wormhole;
+use borsh::BorshSerialize;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BridgeLpOut<'info> {
+    // LP holder moving their position to Sui
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool state account
+    #[account(
+        mut, // total_liquidity decreases
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    // LP token mint
+    #[account(
+        mut, // Supply will decrease
+        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
+        bump = pool.lp_mint_bump,
+        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    // User's LP token account (LP tokens burned from here)
+    #[account(
+        mut,
+        constraint = user_lp_token_account.mint == lp_mint.key() @ ErrorCode::InvalidMint,
+        constraint = user_lp_token_account.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_lp_token_account: Account<'info, TokenAccount>,
+
+    // Program config (outbound message settings)
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    #[account(
+        mut,
+        seeds = [b"Bridge".as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    /// CHECK: Wormhole bridge state account. Data is owned by Wormhole program.
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    // New keypair for the posted message account
+    #[account(mut)]
+    pub wormhole_message: Signer<'info>,
+
+    /// CHECK: This program's Wormhole emitter PDA.
+    #[account(
+        seeds = [EMITTER_SEED],
+        bump
+    )]
+    pub wormhole_emitter: AccountInfo<'info>,
+
+    /// CHECK: Emitter sequence tracker of the core bridge.
+    #[account(
+        mut,
+        seeds = [b"Sequence".as_ref(), wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// CHECK: Core bridge fee collector.
+    #[account(
+        mut,
+        seeds = [b"fee_collector".as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Handler function burning LP tokens on Solana and posting a message so the same share is minted on Sui
+pub fn handler(
+    ctx: Context<BridgeLpOut>,
+    lp_amount: u64,                 // Amount of LP tokens to move
+    sui_recipient: [u8; 32],        // Sui address receiving the LP tokens
+    deadline: i64,                  // Unix timestamp after which the transaction is rejected
+) -> Result<()> {
+    msg!("Bridging LP tokens out...");
+    check_deadline(&Clock::get()?, deadline)?;
+    let pool = &mut ctx.accounts.pool;
+    let ledger = &mut ctx.accounts.ledger;
+
+    require!(pool.status == 0, ErrorCode::PoolPaused);
+    require!(lp_amount > 0, ErrorCode::ZeroLiquidityBurned);
+    require!(lp_amount <= ctx.accounts.user_lp_token_account.amount, ErrorCode::InsufficientLpTokens);
+
+    // Share of the global reserves represented by the LP tokens (recorded in the message)
+    let lp_supply = ledger.total_lp_supply()?;
+    require!(lp_supply > 0, ErrorCode::PoolEmpty);
+    let reserve_a_share = math::share_of_reserve(lp_amount, ledger.total_reserve_a()?, lp_supply)?;
+    let reserve_b_share = math::share_of_reserve(lp_amount, ledger.total_reserve_b()?, lp_supply)?;
+
+    burn_lp_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_lp_token_account.to_account_info(),
+        ctx.accounts.lp_mint.to_account_info(),
+        ctx.accounts.user.to_account_info(), // User authorizes burning their own tokens
+        lp_amount,
+    )?;
+
+    // The LP moves to Sui, the underlying reserves stay where they are
+    pool.total_liquidity = math::checked_sub(pool.total_liquidity, lp_amount)?;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+    ledger.sub_local(0, 0, lp_amount)?;
+    ledger.add_remote(0, 0, lp_amount)?;
+
+    let mut payload = vec![OutboundOperation::LpMigration as u8];
+    LpMigrationPayload {
+        sender_address: ctx.accounts.user.key().to_bytes(),
+        recipient_address: sui_recipient,
+        original_pool_id: pool.pool_id,
+        lp_amount,
+        reserve_a_share,
+        reserve_b_share,
+    }.serialize(&mut payload)?;
+
+    post_outbound_message(
+        OutboundMessageAccounts {
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+            wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+            wormhole_emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+            wormhole_sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+            wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+            payer: ctx.accounts.user.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        &ctx.accounts.config,
+        OutboundOperation::LpMigration,
+        payload,
+        ctx.bumps.wormhole_emitter,
+        MessageSigner::Keypair,
+    )?;
+
+    msg!("LP bridged out: LP={}, share A={}, share B={}", lp_amount, reserve_a_share, reserve_b_share);
+
+    emit_cpi!(LpBridgedOut {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        sui_recipient,
+        lp_amount,
+        reserve_a_share,
+        reserve_b_share,
+    });
+
+    Ok(())
+}
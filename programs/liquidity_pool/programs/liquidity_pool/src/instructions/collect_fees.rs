@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Pool, Config};
+use crate::errors::ErrorCode;
+use crate::events::FeesCollected;
+use crate::instructions::remove_liquidity::transfer_pool_tokens;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CollectFees<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, // Accrued protocol fees are reset
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Authority PDA, seeds checked. Used as signer for vault transfers.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    // Token accounts receiving the fees
+    #[account(
+        mut,
+        constraint = fee_receiver_a.mint == pool.token_a_mint @ ErrorCode::InvalidMint
+    )]
+    pub fee_receiver_a: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_receiver_b.mint == pool.token_b_mint @ ErrorCode::InvalidMint
+    )]
+    pub fee_receiver_b: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Handler function transferring the accrued protocol fees out of the pool vaults
+pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let amount_a = pool.protocol_fee_a;
+    let amount_b = pool.protocol_fee_b;
+
+    if amount_a > 0 {
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_a_account.to_account_info(),
+            ctx.accounts.fee_receiver_a.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            pool.key(),
+            amount_a,
+            ctx.bumps.pool_authority,
+        )?;
+    }
+    if amount_b > 0 {
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_b_account.to_account_info(),
+            ctx.accounts.fee_receiver_b.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            pool.key(),
+            amount_b,
+            ctx.bumps.pool_authority,
+        )?;
+    }
+
+    // Protocol fees were never part of the LP reserves, so the ledger is unchanged
+    pool.protocol_fee_a = 0;
+    pool.protocol_fee_b = 0;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Protocol fees collected: A={}, B={}", amount_a, amount_b);
+
+    emit_cpi!(FeesCollected {
+        pool: pool.key(),
+        admin: ctx.accounts.admin.key(),
+        amount_a,
+        amount_b,
+    });
+
+    Ok(())
+}
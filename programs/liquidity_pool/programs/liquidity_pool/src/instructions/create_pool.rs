@@ -1,18 +1,31 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
-use crate::state::Pool; // Import the Pool state
+use crate::state::{Pool, PoolLedger, Config}; // Import the Pool state
+use crate::errors::ErrorCode;
+use crate::events::PoolCreated;
+use crate::math::FEE_DENOMINATOR;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(fee_percentage: u64, pool_id: [u8; 32])] // Define instruction arguments used in seeds/constraints
 pub struct CreatePool<'info> {
-    // Payer of the transaction and initial pool creator
+    // Config admin, payer of the pool accounts. Pools are admin-created so nobody can squat a
+    // cross-chain pool ID (the ledger PDA) by binding it to mints of their choice.
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
 
     // Pool state account (PDA) - needs to be initialized
     #[account(
         init,
-        payer = creator,
+        payer = admin,
         space = Pool::SIZE, // Use the calculated size from state/pool.rs
         seeds = [b"pool".as_ref(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
         bump
@@ -27,6 +40,16 @@ pub struct CreatePool<'info> {
     )]
     pub pool_authority: AccountInfo<'info>,
 
+    // Global LP ledger (PDA per cross-chain pool ID) - needs to be initialized
+    #[account(
+        init,
+        payer = admin,
+        space = PoolLedger::SIZE,
+        seeds = [b"ledger".as_ref(), pool_id.as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
     // Token mints
     pub token_a_mint: Account<'info, Mint>,
     pub token_b_mint: Account<'info, Mint>,
@@ -34,7 +57,7 @@ pub struct CreatePool<'info> {
     // LP token mint (PDA) - needs to be initialized
     #[account(
         init,
-        payer = creator,
+        payer = admin,
         mint::decimals = 6, // Example: Set LP token decimals (adjust as needed)
         mint::authority = pool_authority,
         seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
@@ -45,7 +68,7 @@ pub struct CreatePool<'info> {
     // Token accounts owned by the pool authority (PDAs) - need to be initialized
     #[account(
         init,
-        payer = creator,
+        payer = admin,
         token::mint = token_a_mint,
         token::authority = pool_authority,
         seeds = [b"token_a".as_ref(), pool.key().as_ref()],
@@ -55,7 +78,7 @@ pub struct CreatePool<'info> {
 
     #[account(
         init,
-        payer = creator,
+        payer = admin,
         token::mint = token_b_mint,
         token::authority = pool_authority,
         seeds = [b"token_b".as_ref(), pool.key().as_ref()],
@@ -63,6 +86,18 @@ pub struct CreatePool<'info> {
     )]
     pub token_b_account: Account<'info, TokenAccount>,
 
+    // Holds the MINIMUM_LIQUIDITY minted with the first deposit. Owned by the system program
+    // address, which can never sign, so the LP tokens in it are permanently locked.
+    #[account(
+        init,
+        payer = admin,
+        token::mint = lp_mint,
+        token::authority = system_program,
+        seeds = [b"locked_lp".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub locked_lp_token_account: Account<'info, TokenAccount>,
+
     // System programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -72,10 +107,10 @@ pub struct CreatePool<'info> {
 // Instruction handler function
 pub fn handler(ctx: Context<CreatePool>, fee_percentage: u64, pool_id: [u8; 32]) -> Result<()> {
     msg!("Creating new liquidity pool...");
+    require!(fee_percentage < FEE_DENOMINATOR, ErrorCode::InvalidFee);
 
     // Get the accounts
     let pool = &mut ctx.accounts.pool;
-    let _creator = &ctx.accounts.creator; // Mark as unused if not needed directly
 
     // Get bumps directly from the Bumps struct generated by #[derive(Accounts)]
     let _authority_bump = ctx.bumps.pool_authority; // Mark as unused
@@ -103,10 +138,27 @@ pub fn handler(ctx: Context<CreatePool>, fee_percentage: u64, pool_id: [u8; 32])
     pool.token_a_bump = token_a_bump;
     pool.token_b_bump = token_b_bump;
 
+    // Initialize the global LP ledger (all reserves and supplies start at zero)
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.pool_id = pool_id;
+    ledger.pool = pool.key();
+    ledger.last_updated_at = pool.last_updated_at;
+    ledger.bump = ctx.bumps.ledger;
+
     msg!("Pool created successfully with ID: {:?}", pool_id);
     msg!("Pool account address: {}", pool.key());
     msg!("Pool authority PDA: {}", pool.authority);
     msg!("LP Mint PDA: {}", pool.lp_mint);
 
+    emit_cpi!(PoolCreated {
+        pool: pool.key(),
+        pool_id,
+        creator: ctx.accounts.admin.key(),
+        token_a_mint: pool.token_a_mint,
+        token_b_mint: pool.token_b_mint,
+        lp_mint: pool.lp_mint,
+        fee_percentage,
+    });
+
     Ok(())
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use crate::state::{Config, DEFAULT_MAX_PRICE_DRIFT_BPS};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    // Payer and initial admin of the program configuration
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Program config account (singleton PDA) - needs to be initialized
+    #[account(
+        init,
+        payer = admin,
+        space = Config::SIZE,
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    // ProgramData of this program: only its upgrade authority may claim the admin role,
+    // so the config can't be initialized by whoever front-runs the deployment
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction handler function
+pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    msg!("Initializing program config...");
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.batch_nonce = 0;
+    config.consistency_levels = Default::default(); // Finalized for every operation
+    config.rent_reimbursement_lamports = 0; // Relayer rent reimbursement disabled by default
+    config.max_price_drift_bps = DEFAULT_MAX_PRICE_DRIFT_BPS;
+    config.bump = ctx.bumps.config;
+
+    msg!("Config initialized with admin: {}", config.admin);
+
+    Ok(())
+}
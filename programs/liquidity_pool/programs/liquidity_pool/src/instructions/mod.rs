@@ -1,9 +1,43 @@
 pub mod create_pool;
 pub mod add_liquidity;
 pub mod remove_liquidity;
+pub mod swap;
+pub mod add_liquidity_single_sided;
+pub mod remove_liquidity_single_sided;
 pub mod process_vaa;
+pub mod process_add_liquidity_vaa;
+pub mod process_remove_liquidity_vaa;
+pub mod process_swap_vaa;
+pub mod process_reserve_snapshot_vaa;
+pub mod bridge_lp_out;
+pub mod process_lp_migration_vaa;
+pub mod initialize_config;
+pub mod update_wormhole_settings;
+pub mod update_relayer_settings;
+pub mod set_emitter_ordering;
+pub mod update_drift_threshold;
+pub mod set_pool_status;
+pub mod collect_fees;
+pub mod set_fee;
 
 pub use create_pool::*;
 pub use add_liquidity::*;
 pub use remove_liquidity::*;
+pub use swap::*;
+pub use add_liquidity_single_sided::*;
+pub use remove_liquidity_single_sided::*;
 pub use process_vaa::*;
+pub use process_add_liquidity_vaa::*;
+pub use process_remove_liquidity_vaa::*;
+pub use process_swap_vaa::*;
+pub use process_reserve_snapshot_vaa::*;
+pub use bridge_lp_out::*;
+pub use process_lp_migration_vaa::*;
+pub use initialize_config::*;
+pub use update_wormhole_settings::*;
+pub use update_relayer_settings::*;
+pub use set_emitter_ordering::*;
+pub use update_drift_threshold::*;
+pub use set_pool_status::*;
+pub use collect_fees::*;
+pub use set_fee::*;
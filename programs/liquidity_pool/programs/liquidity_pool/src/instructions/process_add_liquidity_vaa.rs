@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::state::{Pool, PoolLedger, Config, BridgeRequest, BridgeStatus};
+use crate::math;
+use crate::events::LiquidityAdded;
+use crate::errors::ErrorCode;
+use crate::payloads::{AddLiquidityCompletionPayload, BridgeOperationCode};
+use borsh::BorshDeserialize; // Keep for our custom payload deserialization
+use crate::instructions::add_liquidity::mint_lp_tokens;
+use crate::instructions::process_vaa::{
+    parse_posted_vaa, enforce_sequence_order, record_bridge_request,
+    resolve_recipient_token_account, reimburse_relayer_rent, rent_for, vaa_processed_event,
+};
+use crate::receiver::{invoke_receiver_program, DeliveryCallback, DELIVERY_AUTHORITY_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ProcessAddLiquidityVAA<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Core bridge PostedVAA account. Owner and address (PDA of the body hash) checked in handler.
+    #[account()]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// CHECK: Core bridge signature set that verified the VAA. Checked against the posted VAA in handler.
+    #[account()]
+    pub signature_set: AccountInfo<'info>,
+
+    // Record of this VAA; initialization fails if it was already processed
+    #[account(
+        init,
+        payer = payer,
+        space = BridgeRequest::SIZE,
+        seeds = [b"bridge_request".as_ref(), vaa_hash.as_ref()],
+        bump
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    // Program config (relayer policy). Mutable because rent reimbursements are paid from its lamports.
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Sequence tracker PDA of the VAA's emitter (may be uninitialized). Checked in handler.
+    #[account(mut)]
+    pub sequence_tracker: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Authority PDA, seeds checked. Used as mint authority.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
+        bump = pool.lp_mint_bump,
+        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// CHECK: Recipient address derived from VAA payload (wallet or PDA). Checked in handler.
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Recipient's LP ATA (created if missing) or the payload's target token account. Checked in handler.
+    #[account(mut)]
+    pub recipient_lp_token_account: AccountInfo<'info>,
+
+    // Relayer (payer) LP token account receiving the optional relayer fee
+    #[account(
+        mut,
+        constraint = relayer_lp_token_account.mint == lp_mint.key() @ ErrorCode::InvalidMint,
+        constraint = relayer_lp_token_account.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub relayer_lp_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Receiver program from the VAA payload, only needed when the payload sets one. Checked in handler.
+    #[account(executable)]
+    pub receiver_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: Delivery authority PDA, seeds checked. Holds nothing; only signs receiver program callbacks.
+    #[account(
+        seeds = [DELIVERY_AUTHORITY_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub delivery_authority: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Handler function for completing an AddLiquidity operation initiated on Sui
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessAddLiquidityVAA<'info>>,
+    vaa_hash: [u8; 32]
+) -> Result<()> {
+    msg!("Processing Add Liquidity Completion...");
+
+    let vaa = parse_posted_vaa(&ctx.accounts.posted_vaa, &ctx.accounts.signature_set)?;
+    require!(
+        vaa.operation_code == BridgeOperationCode::AddLiquidityCompletion as u8,
+        ErrorCode::InvalidBridgeOperation
+    );
+    enforce_sequence_order(&ctx.accounts.sequence_tracker, &vaa)?;
+    record_bridge_request(&mut ctx.accounts.bridge_request, &vaa, vaa_hash, ctx.bumps.bridge_request)?;
+
+    let completion_payload = AddLiquidityCompletionPayload::try_from_slice(&vaa.payload)
+        .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+    msg!("Payload: {:?}", completion_payload);
+
+    require!(
+        ctx.accounts.recipient.key().to_bytes() == completion_payload.recipient_address,
+        ErrorCode::RecipientMismatch
+    );
+    require!(
+        ctx.accounts.pool.pool_id == completion_payload.original_pool_id,
+        ErrorCode::PoolIdMismatch
+    );
+
+    // Split the minted amount between the recipient and the relayer
+    let relayer_fee = completion_payload.relayer_fee;
+    let recipient_amount = completion_payload.lp_amount_to_mint
+        .checked_sub(relayer_fee)
+        .ok_or(error!(ErrorCode::RelayerFeeTooHigh))?;
+
+    let recipient_account_rent = resolve_recipient_token_account(
+        completion_payload.target_token_account,
+        &ctx.accounts.recipient_lp_token_account,
+        &ctx.accounts.recipient,
+        &ctx.accounts.lp_mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+    )?;
+
+    mint_lp_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.lp_mint.to_account_info(),
+        ctx.accounts.recipient_lp_token_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.pool.key(),
+        recipient_amount,
+        ctx.bumps.pool_authority,
+    )?;
+    msg!("Minted {} LP tokens to {}", recipient_amount, ctx.accounts.recipient.key());
+
+    if relayer_fee > 0 {
+        let relayer_lp_token_account = ctx.accounts.relayer_lp_token_account.as_ref()
+            .ok_or(error!(ErrorCode::MissingRelayerFeeAccount))?;
+        mint_lp_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.lp_mint.to_account_info(),
+            relayer_lp_token_account.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.pool.key(),
+            relayer_fee,
+            ctx.bumps.pool_authority,
+        )?;
+        msg!("Paid relayer fee of {} LP tokens to {}", relayer_fee, ctx.accounts.payer.key());
+    }
+
+    // Sui received the deposit, Solana minted the LP (recipient share + relayer fee)
+    let pool = &mut ctx.accounts.pool;
+    pool.total_liquidity = pool.total_liquidity
+        .checked_add(completion_payload.lp_amount_to_mint)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.add_remote(completion_payload.amount_a_deposited, completion_payload.amount_b_deposited, 0)?;
+    ledger.add_local(0, 0, completion_payload.lp_amount_to_mint)?;
+
+    // Notify the receiver program (e.g. a vault PDA's program) of the delivery
+    if let Some(receiver_program_id) = completion_payload.receiver_program {
+        let receiver_program = ctx.accounts.receiver_program.as_ref()
+            .ok_or(error!(ErrorCode::ReceiverProgramMismatch))?;
+        require!(receiver_program.key().to_bytes() == receiver_program_id, ErrorCode::ReceiverProgramMismatch);
+
+        invoke_receiver_program(
+            receiver_program,
+            &ctx.accounts.delivery_authority,
+            ctx.accounts.pool.key(),
+            ctx.bumps.delivery_authority,
+            &ctx.accounts.recipient,
+            &[ctx.accounts.recipient_lp_token_account.clone()],
+            ctx.remaining_accounts,
+            &DeliveryCallback {
+                pool_id: ctx.accounts.pool.pool_id,
+                operation_code: vaa.operation_code,
+                wormhole_sequence: vaa.sequence,
+                lp_amount: recipient_amount,
+                amount_a: 0,
+                amount_b: 0,
+            },
+        )?;
+    }
+
+    // Reimburse the relayer for the rent spent on the BridgeRequest and recipient accounts
+    let rent_paid = math::checked_add(rent_for(BridgeRequest::SIZE)?, recipient_account_rent)?;
+    reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+    // Deposited on Sui, LP minted here (recipient share + relayer fee)
+    emit_cpi!(LiquidityAdded {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.recipient.key(),
+        amount_a_desired: completion_payload.amount_a_deposited,
+        amount_b_desired: completion_payload.amount_b_deposited,
+        amount_a: completion_payload.amount_a_deposited,
+        amount_b: completion_payload.amount_b_deposited,
+        lp_tokens_minted: completion_payload.lp_amount_to_mint,
+    });
+    emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Completed));
+
+    msg!("VAA processed successfully.");
+    Ok(())
+}
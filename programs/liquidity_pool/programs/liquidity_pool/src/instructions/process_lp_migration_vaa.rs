@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use crate::state::{Pool, PoolLedger, Config, BridgeRequest, BridgeStatus};
+use crate::math;
+use crate::errors::ErrorCode;
+use crate::payloads::{LpMigrationPayload, BridgeOperationCode};
+use borsh::BorshDeserialize;
+use crate::instructions::add_liquidity::mint_lp_tokens;
+use crate::instructions::process_vaa::{
+    parse_posted_vaa, enforce_sequence_order, record_bridge_request,
+    resolve_recipient_token_account, reimburse_relayer_rent, rent_for, vaa_processed_event,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ProcessLpMigrationVAA<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Core bridge PostedVAA account. Owner and address (PDA of the body hash) checked in handler.
+    #[account()]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// CHECK: Core bridge signature set that verified the VAA. Checked against the posted VAA in handler.
+    #[account()]
+    pub signature_set: AccountInfo<'info>,
+
+    // Record of this VAA; initialization fails if it was already processed
+    #[account(
+        init,
+        payer = payer,
+        space = BridgeRequest::SIZE,
+        seeds = [b"bridge_request".as_ref(), vaa_hash.as_ref()],
+        bump
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    // Program config (relayer policy). Mutable because rent reimbursements are paid from its lamports.
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Sequence tracker PDA of the VAA's emitter (may be uninitialized). Checked in handler.
+    #[account(mut)]
+    pub sequence_tracker: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Authority PDA, seeds checked. Used as mint authority.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
+        bump = pool.lp_mint_bump,
+        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// CHECK: Recipient address derived from VAA payload (wallet or PDA). Checked in handler.
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Recipient's LP ATA (created if missing). Checked in handler.
+    #[account(mut)]
+    pub recipient_lp_token_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Handler function minting LP tokens on Solana for an LP position burned on Sui
+pub fn handler(
+    ctx: Context<ProcessLpMigrationVAA>,
+    vaa_hash: [u8; 32]
+) -> Result<()> {
+    msg!("Processing LP Migration...");
+
+    let vaa = parse_posted_vaa(&ctx.accounts.posted_vaa, &ctx.accounts.signature_set)?;
+    require!(
+        vaa.operation_code == BridgeOperationCode::LpMigration as u8,
+        ErrorCode::InvalidBridgeOperation
+    );
+    enforce_sequence_order(&ctx.accounts.sequence_tracker, &vaa)?;
+    record_bridge_request(&mut ctx.accounts.bridge_request, &vaa, vaa_hash, ctx.bumps.bridge_request)?;
+
+    let migration = LpMigrationPayload::try_from_slice(&vaa.payload)
+        .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+    msg!("Payload: {:?}", migration);
+
+    require!(
+        ctx.accounts.recipient.key().to_bytes() == migration.recipient_address,
+        ErrorCode::RecipientMismatch
+    );
+    require!(
+        ctx.accounts.pool.pool_id == migration.original_pool_id,
+        ErrorCode::PoolIdMismatch
+    );
+    require!(migration.lp_amount > 0, ErrorCode::ZeroLiquidityMinted);
+
+    let recipient_account_rent = resolve_recipient_token_account(
+        None,
+        &ctx.accounts.recipient_lp_token_account,
+        &ctx.accounts.recipient,
+        &ctx.accounts.lp_mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+    )?;
+
+    mint_lp_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.lp_mint.to_account_info(),
+        ctx.accounts.recipient_lp_token_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.pool.key(),
+        migration.lp_amount,
+        ctx.bumps.pool_authority,
+    )?;
+    msg!("Minted {} migrated LP tokens to {}", migration.lp_amount, ctx.accounts.recipient.key());
+
+    // The LP moves from Sui to Solana, the underlying reserves stay where they are
+    let pool = &mut ctx.accounts.pool;
+    pool.total_liquidity = pool.total_liquidity
+        .checked_add(migration.lp_amount)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.sub_remote(0, 0, migration.lp_amount)?;
+    ledger.add_local(0, 0, migration.lp_amount)?;
+
+    // Reimburse the relayer for the rent spent on the BridgeRequest and recipient accounts
+    let rent_paid = math::checked_add(rent_for(BridgeRequest::SIZE)?, recipient_account_rent)?;
+    reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+    emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Completed));
+
+    msg!("VAA processed successfully.");
+    Ok(())
+}
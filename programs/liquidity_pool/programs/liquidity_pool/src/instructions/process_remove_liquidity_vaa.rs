@@ -0,0 +1,306 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::state::{Pool, PoolLedger, Config, BridgeRequest, BridgeStatus};
+use crate::math;
+use crate::events::LiquidityRemoved;
+use crate::errors::ErrorCode;
+use crate::payloads::{RemoveLiquidityCompletionPayload, BridgeOperationCode};
+use borsh::BorshDeserialize; // Keep for our custom payload deserialization
+use crate::instructions::remove_liquidity::transfer_pool_tokens;
+use crate::instructions::process_vaa::{
+    parse_posted_vaa, enforce_sequence_order, record_bridge_request,
+    resolve_recipient_token_account, reimburse_relayer_rent, rent_for, vaa_processed_event,
+};
+use crate::receiver::{invoke_receiver_program, DeliveryCallback, DELIVERY_AUTHORITY_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ProcessRemoveLiquidityVAA<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Core bridge PostedVAA account. Owner and address (PDA of the body hash) checked in handler.
+    #[account()]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// CHECK: Core bridge signature set that verified the VAA. Checked against the posted VAA in handler.
+    #[account()]
+    pub signature_set: AccountInfo<'info>,
+
+    // Record of this VAA; initialization fails if it was already processed
+    #[account(
+        init,
+        payer = payer,
+        space = BridgeRequest::SIZE,
+        seeds = [b"bridge_request".as_ref(), vaa_hash.as_ref()],
+        bump
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    // Program config (relayer policy). Mutable because rent reimbursements are paid from its lamports.
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Sequence tracker PDA of the VAA's emitter (may be uninitialized). Checked in handler.
+    #[account(mut)]
+    pub sequence_tracker: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Authority PDA, seeds checked. Used as signer for vault transfers.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    #[account(address = pool.token_a_mint @ ErrorCode::InvalidMint)]
+    pub token_a_mint: Account<'info, Mint>,
+    #[account(address = pool.token_b_mint @ ErrorCode::InvalidMint)]
+    pub token_b_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Recipient address derived from VAA payload (wallet or PDA). Checked in handler.
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Recipient's token A ATA (created if missing) or the payload's target token account. Checked in handler.
+    #[account(mut)]
+    pub recipient_token_a_account: AccountInfo<'info>,
+
+    /// CHECK: Recipient's token B ATA (created if missing) or the payload's target token account. Checked in handler.
+    #[account(mut)]
+    pub recipient_token_b_account: AccountInfo<'info>,
+
+    // Relayer (payer) token accounts receiving the optional relayer fee.
+    // Only required when the payload carries a non-zero fee in that token.
+    #[account(
+        mut,
+        constraint = relayer_token_a_account.mint == pool.token_a_mint @ ErrorCode::InvalidMint,
+        constraint = relayer_token_a_account.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub relayer_token_a_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = relayer_token_b_account.mint == pool.token_b_mint @ ErrorCode::InvalidMint,
+        constraint = relayer_token_b_account.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub relayer_token_b_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Receiver program from the VAA payload, only needed when the payload sets one. Checked in handler.
+    #[account(executable)]
+    pub receiver_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: Delivery authority PDA, seeds checked. Holds nothing; only signs receiver program callbacks.
+    #[account(
+        seeds = [DELIVERY_AUTHORITY_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub delivery_authority: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Handler function for completing a RemoveLiquidity operation initiated on Sui
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessRemoveLiquidityVAA<'info>>,
+    vaa_hash: [u8; 32]
+) -> Result<()> {
+    msg!("Processing Remove Liquidity Completion...");
+
+    let vaa = parse_posted_vaa(&ctx.accounts.posted_vaa, &ctx.accounts.signature_set)?;
+    require!(
+        vaa.operation_code == BridgeOperationCode::RemoveLiquidityCompletion as u8,
+        ErrorCode::InvalidBridgeOperation
+    );
+    enforce_sequence_order(&ctx.accounts.sequence_tracker, &vaa)?;
+    record_bridge_request(&mut ctx.accounts.bridge_request, &vaa, vaa_hash, ctx.bumps.bridge_request)?;
+
+    let completion_payload = RemoveLiquidityCompletionPayload::try_from_slice(&vaa.payload)
+        .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+    msg!("Payload: {:?}", completion_payload);
+
+    require!(
+        ctx.accounts.recipient.key().to_bytes() == completion_payload.recipient_address,
+        ErrorCode::RecipientMismatch
+    );
+    require!(
+        ctx.accounts.pool.pool_id == completion_payload.original_pool_id,
+        ErrorCode::PoolIdMismatch
+    );
+
+    // Split the released amounts between the recipient and the relayer
+    let relayer_fee_a = completion_payload.relayer_fee_a;
+    let relayer_fee_b = completion_payload.relayer_fee_b;
+    let recipient_amount_a = completion_payload.amount_a_to_transfer
+        .checked_sub(relayer_fee_a)
+        .ok_or(error!(ErrorCode::RelayerFeeTooHigh))?;
+    let recipient_amount_b = completion_payload.amount_b_to_transfer
+        .checked_sub(relayer_fee_b)
+        .ok_or(error!(ErrorCode::RelayerFeeTooHigh))?;
+
+    let recipient_a_rent = resolve_recipient_token_account(
+        completion_payload.target_token_a_account,
+        &ctx.accounts.recipient_token_a_account,
+        &ctx.accounts.recipient,
+        &ctx.accounts.token_a_mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+    )?;
+    let recipient_b_rent = resolve_recipient_token_account(
+        completion_payload.target_token_b_account,
+        &ctx.accounts.recipient_token_b_account,
+        &ctx.accounts.recipient,
+        &ctx.accounts.token_b_mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+    )?;
+
+    transfer_pool_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_a_account.to_account_info(),
+        ctx.accounts.recipient_token_a_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.pool.key(),
+        recipient_amount_a,
+        ctx.bumps.pool_authority,
+    )?;
+    msg!("Transferred {} Token A to {}", recipient_amount_a, ctx.accounts.recipient.key());
+
+    transfer_pool_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_b_account.to_account_info(),
+        ctx.accounts.recipient_token_b_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.pool.key(),
+        recipient_amount_b,
+        ctx.bumps.pool_authority,
+    )?;
+    msg!("Transferred {} Token B to {}", recipient_amount_b, ctx.accounts.recipient.key());
+
+    if relayer_fee_a > 0 {
+        let relayer_token_a_account = ctx.accounts.relayer_token_a_account.as_ref()
+            .ok_or(error!(ErrorCode::MissingRelayerFeeAccount))?;
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_a_account.to_account_info(),
+            relayer_token_a_account.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.pool.key(),
+            relayer_fee_a,
+            ctx.bumps.pool_authority,
+        )?;
+        msg!("Paid relayer fee of {} Token A to {}", relayer_fee_a, ctx.accounts.payer.key());
+    }
+
+    if relayer_fee_b > 0 {
+        let relayer_token_b_account = ctx.accounts.relayer_token_b_account.as_ref()
+            .ok_or(error!(ErrorCode::MissingRelayerFeeAccount))?;
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_b_account.to_account_info(),
+            relayer_token_b_account.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.pool.key(),
+            relayer_fee_b,
+            ctx.bumps.pool_authority,
+        )?;
+        msg!("Paid relayer fee of {} Token B to {}", relayer_fee_b, ctx.accounts.payer.key());
+    }
+
+    // Sui burned the LP, Solana paid out the tokens (recipient share + relayer fees)
+    ctx.accounts.pool.last_updated_at = Clock::get()?.unix_timestamp;
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.sub_local(completion_payload.amount_a_to_transfer, completion_payload.amount_b_to_transfer, 0)?;
+    ledger.sub_remote(0, 0, completion_payload.lp_amount_burned)?;
+
+    // Notify the receiver program (e.g. a vault PDA's program) of the delivery
+    if let Some(receiver_program_id) = completion_payload.receiver_program {
+        let receiver_program = ctx.accounts.receiver_program.as_ref()
+            .ok_or(error!(ErrorCode::ReceiverProgramMismatch))?;
+        require!(receiver_program.key().to_bytes() == receiver_program_id, ErrorCode::ReceiverProgramMismatch);
+
+        invoke_receiver_program(
+            receiver_program,
+            &ctx.accounts.delivery_authority,
+            ctx.accounts.pool.key(),
+            ctx.bumps.delivery_authority,
+            &ctx.accounts.recipient,
+            &[
+                ctx.accounts.recipient_token_a_account.clone(),
+                ctx.accounts.recipient_token_b_account.clone(),
+            ],
+            ctx.remaining_accounts,
+            &DeliveryCallback {
+                pool_id: ctx.accounts.pool.pool_id,
+                operation_code: vaa.operation_code,
+                wormhole_sequence: vaa.sequence,
+                lp_amount: 0,
+                amount_a: recipient_amount_a,
+                amount_b: recipient_amount_b,
+            },
+        )?;
+    }
+
+    // Reimburse the relayer for the rent spent on the BridgeRequest and recipient accounts
+    let rent_paid = math::checked_add(
+        rent_for(BridgeRequest::SIZE)?,
+        math::checked_add(recipient_a_rent, recipient_b_rent)?,
+    )?;
+    reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+    // LP burned on Sui, tokens paid out here (recipient share + relayer fees)
+    emit_cpi!(LiquidityRemoved {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.recipient.key(),
+        amount_a: completion_payload.amount_a_to_transfer,
+        amount_b: completion_payload.amount_b_to_transfer,
+        lp_tokens_burned: completion_payload.lp_amount_burned,
+    });
+    emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Completed));
+
+    msg!("VAA processed successfully.");
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Pool, Config, RemotePoolState, BridgeStatus};
+use crate::errors::ErrorCode;
+use crate::payloads::{ReserveSnapshotPayload, BridgeOperationCode};
+use crate::math::FEE_DENOMINATOR;
+use borsh::BorshDeserialize;
+use crate::instructions::process_vaa::{
+    parse_posted_vaa, enforce_sequence_order, reimburse_relayer_rent, rent_for,
+    vaa_processed_event,
+};
+
+// Snapshots are ordered by their Sui timestamp, which also rejects replays,
+// so unlike the other bridge operations no BridgeRequest is created per snapshot.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProcessReserveSnapshotVAA<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Core bridge PostedVAA account. Owner and address (PDA of the body hash) checked in handler.
+    #[account()]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// CHECK: Core bridge signature set that verified the VAA. Checked against the posted VAA in handler.
+    #[account()]
+    pub signature_set: AccountInfo<'info>,
+
+    // Program config (drift threshold, relayer policy)
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Sequence tracker PDA of the VAA's emitter (may be uninitialized). Checked in handler.
+    #[account(mut)]
+    pub sequence_tracker: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Latest Sui pool state, created with the first snapshot. Pricing input and drift alarm only:
+    // the ledger tracks the Sui side from the bridged deposits and withdrawals themselves.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RemotePoolState::SIZE,
+        seeds = [b"remote_pool".as_ref(), pool.pool_id.as_ref()],
+        bump
+    )]
+    pub remote_pool_state: Account<'info, RemotePoolState>,
+
+    #[account(
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Handler function storing a reserve snapshot of the mirrored Sui pool
+pub fn handler(
+    ctx: Context<ProcessReserveSnapshotVAA>,
+    _vaa_hash: [u8; 32]
+) -> Result<()> {
+    msg!("Processing Reserve Snapshot...");
+
+    let vaa = parse_posted_vaa(&ctx.accounts.posted_vaa, &ctx.accounts.signature_set)?;
+    require!(
+        vaa.operation_code == BridgeOperationCode::ReserveSnapshot as u8,
+        ErrorCode::InvalidBridgeOperation
+    );
+    enforce_sequence_order(&ctx.accounts.sequence_tracker, &vaa)?;
+
+    let snapshot = ReserveSnapshotPayload::try_from_slice(&vaa.payload)
+        .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+    msg!("Payload: {:?}", snapshot);
+
+    require!(
+        ctx.accounts.pool.pool_id == snapshot.original_pool_id,
+        ErrorCode::PoolIdMismatch
+    );
+
+    let remote_pool_state = &mut ctx.accounts.remote_pool_state;
+    require!(snapshot.timestamp > remote_pool_state.snapshot_timestamp, ErrorCode::StaleReserveSnapshot);
+    // Only the first snapshot (stale check above: timestamps are > 0) pays for the account
+    let created_remote_pool_state = remote_pool_state.snapshot_timestamp == 0;
+
+    // Compare the Sui price with the Solana pool's current price
+    let (local_reserve_a, local_reserve_b) = ctx.accounts.pool.lp_reserves(
+        ctx.accounts.token_a_account.amount,
+        ctx.accounts.token_b_account.amount,
+    );
+    let drift_bps = calculate_price_drift_bps(
+        local_reserve_a,
+        local_reserve_b,
+        snapshot.reserve_a,
+        snapshot.reserve_b,
+    )?;
+    let drift_alarm = drift_bps > ctx.accounts.config.max_price_drift_bps;
+    if drift_alarm {
+        msg!(
+            "DRIFT ALARM: pool prices diverge by {} bps (threshold {} bps)",
+            drift_bps,
+            ctx.accounts.config.max_price_drift_bps
+        );
+    }
+
+    remote_pool_state.pool_id = snapshot.original_pool_id;
+    remote_pool_state.reserve_a = snapshot.reserve_a;
+    remote_pool_state.reserve_b = snapshot.reserve_b;
+    remote_pool_state.lp_supply = snapshot.lp_supply;
+    remote_pool_state.snapshot_timestamp = snapshot.timestamp;
+    remote_pool_state.received_at = Clock::get()?.unix_timestamp;
+    remote_pool_state.wormhole_sequence = vaa.sequence;
+    remote_pool_state.price_drift_bps = drift_bps;
+    remote_pool_state.drift_alarm = drift_alarm;
+    remote_pool_state.bump = ctx.bumps.remote_pool_state;
+
+    let rent_paid = if created_remote_pool_state { rent_for(RemotePoolState::SIZE)? } else { 0 };
+    reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+    msg!("Reserve snapshot stored: A={}, B={}, LP={}, drift={} bps", snapshot.reserve_a, snapshot.reserve_b, snapshot.lp_supply, drift_bps);
+
+    emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Completed));
+    Ok(())
+}
+
+// Helper function computing the relative price difference between two pools in basis points.
+// drift = |price_remote - price_local| / price_local with price = reserve_b / reserve_a.
+// Returns 0 when either pool is empty (no price to compare).
+pub fn calculate_price_drift_bps(
+    local_reserve_a: u64,
+    local_reserve_b: u64,
+    remote_reserve_a: u64,
+    remote_reserve_b: u64,
+) -> Result<u64> {
+    if local_reserve_a == 0 || local_reserve_b == 0 || remote_reserve_a == 0 || remote_reserve_b == 0 {
+        return Ok(0);
+    }
+
+    // Cross-multiply to compare b_r / a_r with b_l / a_l without division
+    let remote_cross = (remote_reserve_b as u128)
+        .checked_mul(local_reserve_a as u128)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    let local_cross = (local_reserve_b as u128)
+        .checked_mul(remote_reserve_a as u128)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    let difference = remote_cross.abs_diff(local_cross);
+
+    let drift = difference
+        .checked_mul(FEE_DENOMINATOR as u128)
+        .ok_or(error!(ErrorCode::Overflow))?
+        / local_cross;
+
+    Ok(u64::try_from(drift).unwrap_or(u64::MAX))
+}
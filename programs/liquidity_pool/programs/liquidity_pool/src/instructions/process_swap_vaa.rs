@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::state::{Pool, PoolLedger, Config, BridgeRequest, BridgeStatus, OutboundOperation};
+use crate::errors::ErrorCode;
+use crate::payloads::{SwapIntentPayload, SwapRefundPayload, BridgeOperationCode};
+use crate::outbound::{post_outbound_message, MessageSigner, OutboundMessageAccounts, EMITTER_SEED};
+use wormhole_anchor_sdk::wormhole;
+use borsh::{BorshDeserialize, BorshSerialize};
+use crate::instructions::remove_liquidity::transfer_pool_tokens;
+use crate::instructions::swap::{quote_swap_leg, settle_remote_swap_leg};
+use crate::math;
+use crate::events;
+use crate::instructions::process_vaa::{
+    parse_posted_vaa, enforce_sequence_order, record_bridge_request,
+    resolve_recipient_token_account, reimburse_relayer_rent, rent_for, vaa_processed_event,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ProcessSwapVAA<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    #[account(
+        mut, // Written by the core bridge when a refund message is posted
+        seeds = [b"Bridge".as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    /// CHECK: Wormhole bridge state account. Data is owned by Wormhole program.
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// CHECK: Core bridge PostedVAA account. Owner and address (PDA of the body hash) checked in handler.
+    #[account()]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// CHECK: Core bridge signature set that verified the VAA. Checked against the posted VAA in handler.
+    #[account()]
+    pub signature_set: AccountInfo<'info>,
+
+    // Record of this VAA; initialization fails if it was already processed
+    #[account(
+        init,
+        payer = payer,
+        space = BridgeRequest::SIZE,
+        seeds = [b"bridge_request".as_ref(), vaa_hash.as_ref()],
+        bump
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    // Program config (relayer policy, outbound message settings)
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Sequence tracker PDA of the VAA's emitter (may be uninitialized). Checked in handler.
+    #[account(mut)]
+    pub sequence_tracker: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Authority PDA, seeds checked. Used as signer for vault transfers.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    #[account(address = pool.token_a_mint @ ErrorCode::InvalidMint)]
+    pub token_a_mint: Account<'info, Mint>,
+    #[account(address = pool.token_b_mint @ ErrorCode::InvalidMint)]
+    pub token_b_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Recipient address derived from VAA payload (wallet or PDA). Checked in handler.
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Recipient's output token ATA (created if missing) or the payload's target token account. Checked in handler.
+    #[account(mut)]
+    pub recipient_token_account: AccountInfo<'info>,
+
+    // Relayer (payer) output token account receiving the optional relayer fee
+    #[account(
+        mut,
+        constraint = relayer_token_account.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub relayer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Refund message account, created by the core bridge only when the swap is refunded.
+    #[account(
+        mut,
+        seeds = [b"refund".as_ref(), vaa_hash.as_ref()],
+        bump
+    )]
+    pub wormhole_message: AccountInfo<'info>,
+
+    /// CHECK: This program's Wormhole emitter PDA.
+    #[account(
+        seeds = [EMITTER_SEED],
+        bump
+    )]
+    pub wormhole_emitter: AccountInfo<'info>,
+
+    /// CHECK: Emitter sequence tracker of the core bridge.
+    #[account(
+        mut,
+        seeds = [b"Sequence".as_ref(), wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// CHECK: Core bridge fee collector.
+    #[account(
+        mut,
+        seeds = [b"fee_collector".as_ref()],
+        bump,
+        seeds::program = wormhole::program::ID
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Handler function executing a swap intent initiated on Sui against the Solana pool.
+// If the minimum output or the relayer fee can't be met (or the pool is paused, or the intent's deadline
+// has passed) a refund message is posted back to Sui.
+pub fn handler(
+    ctx: Context<ProcessSwapVAA>,
+    vaa_hash: [u8; 32]
+) -> Result<()> {
+    msg!("Processing Swap Intent...");
+
+    let vaa = parse_posted_vaa(&ctx.accounts.posted_vaa, &ctx.accounts.signature_set)?;
+    require!(
+        vaa.operation_code == BridgeOperationCode::SwapIntent as u8,
+        ErrorCode::InvalidBridgeOperation
+    );
+    enforce_sequence_order(&ctx.accounts.sequence_tracker, &vaa)?;
+    record_bridge_request(&mut ctx.accounts.bridge_request, &vaa, vaa_hash, ctx.bumps.bridge_request)?;
+
+    let intent = SwapIntentPayload::try_from_slice(&vaa.payload)
+        .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
+    msg!("Payload: {:?}", intent);
+
+    require!(
+        ctx.accounts.recipient.key().to_bytes() == intent.recipient_address,
+        ErrorCode::RecipientMismatch
+    );
+    require!(
+        ctx.accounts.pool.pool_id == intent.original_pool_id,
+        ErrorCode::PoolIdMismatch
+    );
+    require!(intent.amount_in > 0, ErrorCode::ZeroSwapAmount);
+
+    // Priced like `swap`. A quote that can't be computed (e.g. empty pool) is treated like an unmet
+    // minimum, as is one whose protocol fee can't be set aside in the Solana vault of the input token.
+    // An intent relayed after its deadline is never executed, whatever the price.
+    let expired = Clock::get()?.unix_timestamp > intent.deadline;
+    let (local_a, local_b) = (ctx.accounts.ledger.local_reserve_a, ctx.accounts.ledger.local_reserve_b);
+    let local_reserve_in = if intent.a_to_b { local_a } else { local_b };
+    let quote = if ctx.accounts.pool.status == 0 && !expired {
+        quote_swap_leg(
+            &ctx.accounts.pool,
+            ctx.accounts.token_a_account.amount,
+            ctx.accounts.token_b_account.amount,
+            intent.amount_in,
+            intent.a_to_b,
+        )
+        .ok()
+        .filter(|quote| quote.protocol_fee <= local_reserve_in)
+    } else {
+        None
+    };
+    let amount_out = quote.map_or(0, |quote| quote.amount_out);
+
+    // An output that doesn't cover the relayer fee is refunded too: reverting would leave the intent
+    // unprocessed and block the emitter's ordered sequence
+    let required_out = intent.min_amount_out.max(intent.relayer_fee);
+    if amount_out == 0 || amount_out < required_out {
+        msg!(
+            "Swap intent can't be executed (out={}, min={}, relayer_fee={}, expired={}), refunding on Sui",
+            amount_out,
+            intent.min_amount_out,
+            intent.relayer_fee,
+            expired
+        );
+
+        let mut refund_payload = vec![OutboundOperation::SwapRefund as u8];
+        SwapRefundPayload {
+            refund_address: intent.refund_address,
+            original_pool_id: intent.original_pool_id,
+            amount_in: intent.amount_in,
+            a_to_b: intent.a_to_b,
+            intent_sequence: vaa.sequence,
+        }.serialize(&mut refund_payload)?;
+
+        post_outbound_message(
+            OutboundMessageAccounts {
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+                wormhole_emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                wormhole_sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &ctx.accounts.config,
+            OutboundOperation::SwapRefund,
+            refund_payload,
+            ctx.bumps.wormhole_emitter,
+            MessageSigner::Pda(&[b"refund".as_ref(), vaa_hash.as_ref(), &[ctx.bumps.wormhole_message]]),
+        )?;
+
+        ctx.accounts.bridge_request.status = BridgeStatus::Failed;
+        // The relayer paid for the BridgeRequest and the refund message account
+        let rent_paid = math::checked_add(rent_for(BridgeRequest::SIZE)?, ctx.accounts.wormhole_message.lamports())?;
+        reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+        emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Failed));
+
+        msg!("VAA processed successfully (refunded).");
+        return Ok(());
+    }
+
+    let (pool_source, output_mint) = if intent.a_to_b {
+        (&ctx.accounts.token_b_account, &ctx.accounts.token_b_mint)
+    } else {
+        (&ctx.accounts.token_a_account, &ctx.accounts.token_a_mint)
+    };
+
+    // Split the output between the recipient and the relayer
+    let relayer_fee = intent.relayer_fee;
+    let recipient_amount = amount_out
+        .checked_sub(relayer_fee)
+        .ok_or(error!(ErrorCode::RelayerFeeTooHigh))?;
+
+    let recipient_account_rent = resolve_recipient_token_account(
+        intent.target_token_account,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.recipient,
+        &output_mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+    )?;
+
+    transfer_pool_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        pool_source.to_account_info(),
+        ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.pool.key(),
+        recipient_amount,
+        ctx.bumps.pool_authority,
+    )?;
+    msg!("Transferred {} output tokens to {}", recipient_amount, ctx.accounts.recipient.key());
+
+    if relayer_fee > 0 {
+        let relayer_token_account = ctx.accounts.relayer_token_account.as_ref()
+            .ok_or(error!(ErrorCode::MissingRelayerFeeAccount))?;
+        require!(relayer_token_account.mint == output_mint.key(), ErrorCode::InvalidMint);
+        transfer_pool_tokens(
+            ctx.accounts.token_program.to_account_info(),
+            pool_source.to_account_info(),
+            relayer_token_account.to_account_info(),
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.pool.key(),
+            relayer_fee,
+            ctx.bumps.pool_authority,
+        )?;
+        msg!("Paid relayer fee of {} output tokens to {}", relayer_fee, ctx.accounts.payer.key());
+    }
+
+    // Sui received the input, Solana paid out the output (recipient share + relayer fee)
+    // An intent without a quote was refunded above
+    let quote = quote.ok_or(error!(ErrorCode::PoolEmpty))?;
+    settle_remote_swap_leg(&mut ctx.accounts.pool, &mut ctx.accounts.ledger, intent.amount_in, intent.a_to_b, &quote)?;
+    ctx.accounts.pool.last_updated_at = Clock::get()?.unix_timestamp;
+
+    // Reimburse the relayer for the rent spent on the BridgeRequest and recipient accounts
+    let rent_paid = math::checked_add(rent_for(BridgeRequest::SIZE)?, recipient_account_rent)?;
+    reimburse_relayer_rent(&ctx.accounts.config, &ctx.accounts.payer.to_account_info(), rent_paid)?;
+
+    msg!("Cross-chain swap executed: in={}, out={}, a_to_b={}", intent.amount_in, amount_out, intent.a_to_b);
+
+    emit_cpi!(events::Swap {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.recipient.key(),
+        a_to_b: intent.a_to_b,
+        amount_in: intent.amount_in,
+        amount_out,
+        protocol_fee: quote.protocol_fee,
+    });
+    emit_cpi!(vaa_processed_event(ctx.accounts.pool.key(), &vaa, BridgeStatus::Completed));
+    Ok(())
+}
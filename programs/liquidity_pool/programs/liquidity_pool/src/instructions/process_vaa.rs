@@ -1,235 +1,273 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+    associated_token::{self, get_associated_token_address},
+    token::{self, TokenAccount},
 };
-use crate::state::{Pool, BridgeRequest, BridgeStatus};
+use crate::state::{Config, SequenceTracker, BridgeRequest, BridgeStatus, MAX_BRIDGE_PAYLOAD_SIZE};
 use crate::errors::ErrorCode;
-use crate::payloads::{AddLiquidityCompletionPayload, RemoveLiquidityCompletionPayload};
-use wormhole_anchor_sdk::wormhole; // Keep anchor sdk import for BridgeData etc.
-use wormhole_vaas::{Vaa, Readable, payloads::PayloadKind}; // Import Vaa, Readable, and PayloadKind
-use std::io::Cursor; // Import Cursor for reading from slice
-use borsh::BorshDeserialize; // Keep for our custom payload deserialization
-use crate::instructions::add_liquidity::mint_lp_tokens;
-use crate::instructions::remove_liquidity::transfer_pool_tokens;
+use crate::events::VaaProcessed;
 use hex; // Import hex for encoding
+use borsh::BorshDeserialize;
+use wormhole_anchor_sdk::wormhole;
+
+// Shared VAA handling for the per-operation `process_*_vaa` instructions.
+// Each bridge operation has its own Accounts struct so it only touches (and creates) the accounts it uses.
+
+// PostedVAA accounts of the core bridge start with this magic instead of an Anchor discriminator
+const POSTED_VAA_MAGIC: &[u8] = b"vaa";
+
+// Wormhole PostedVAA account (PDA seeded with [b"PostedVAA", vaa_hash]), written by the core bridge's
+// post_vaa once the guardian signatures are verified
+#[derive(BorshDeserialize)]
+struct PostedVaaData {
+    _vaa_version: u8,
+    consistency_level: u8,
+    vaa_time: u32,                 // Timestamp of the VAA body
+    signature_set: [u8; 32],       // Signature set account that verified the VAA
+    _submission_time: u32,
+    nonce: u32,
+    sequence: u64,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    payload: Vec<u8>,
+}
 
-#[derive(Accounts)]
-pub struct ProcessVAA<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    #[account(
-        seeds = [b"Bridge".as_ref()],
-        bump,
-        seeds::program = wormhole::program::ID // Assuming wormhole program ID is correctly imported/defined
-    )]
-    /// CHECK: Wormhole bridge state account. Address is checked against wormhole::program::ID if needed. Data is owned by Wormhole program.
-    pub wormhole_bridge: AccountInfo<'info>, // Changed from Account<'info, wormhole::BridgeData>
-
-    /// CHECK: Posted VAA account. Data is manually deserialized and verified.
-    /// Client is responsible for passing the correct account address.
-    #[account()]
-    pub posted_vaa: AccountInfo<'info>, // Load as AccountInfo
-
-    #[account(
-        mut,
-        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
-        bump = pool.bump
-    )]
-    pub pool: Account<'info, Pool>,
-
-    /// CHECK: Authority PDA, seeds checked. Used as signer if needed.
-    #[account(
-        seeds = [b"authority".as_ref(), pool.key().as_ref()],
-        bump
-    )]
-    pub pool_authority: AccountInfo<'info>,
-
-    #[account(address = pool.token_a_mint @ ErrorCode::InvalidMint)]
-    pub token_a_mint: Account<'info, Mint>,
-    #[account(address = pool.token_b_mint @ ErrorCode::InvalidMint)]
-    pub token_b_mint: Account<'info, Mint>,
-
-    #[account(
-        mut,
-        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
-        bump = pool.token_a_bump,
-        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
-    )]
-    pub token_a_account: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
-        bump = pool.token_b_bump,
-        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
-    )]
-    pub token_b_account: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
-        bump = pool.lp_mint_bump,
-        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
-    )]
-    pub lp_mint: Account<'info, Mint>,
-
-    /// CHECK: Recipient address derived from VAA payload. Account type checked in handler.
-    #[account(mut)]
-    pub recipient: AccountInfo<'info>,
-
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = lp_mint,
-        associated_token::authority = recipient
-    )]
-    pub recipient_lp_token_account: Account<'info, TokenAccount>,
-
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = token_a_mint,
-        associated_token::authority = recipient
-    )]
-    pub recipient_token_a_account: Account<'info, TokenAccount>,
-
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = token_b_mint,
-        associated_token::authority = recipient
-    )]
-    pub recipient_token_b_account: Account<'info, TokenAccount>,
-
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub rent: Sysvar<'info, Rent>,
+// Wormhole SignatureSet account (keypair account of the core bridge, no magic): the guardians of
+// `guardian_set_index` whose signatures of the VAA `hash` were verified by verify_signatures
+#[derive(BorshDeserialize)]
+struct SignatureSetData {
+    _signatures: Vec<bool>,
+    hash: [u8; 32],
+    guardian_set_index: u32,
 }
 
+// Fields of a posted VAA needed by the bridge operation handlers
+pub struct ParsedVaa {
+    pub hash: [u8; 32],           // keccak256 of the VAA body
+    pub guardian_set_index: u32,  // Guardian set that signed the VAA
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub operation_code: u8,   // First payload byte (BridgeOperationCode)
+    pub payload: Vec<u8>,     // Operation-specific payload (after the operation code)
+}
 
-pub fn handler(
-    ctx: Context<ProcessVAA>,
-    _vaa_hash: [u8; 32]
-) -> Result<()> {
-    msg!("Processing VAA...");
-
-    // --- VAA Verification ---
-    // Manually deserialize and verify the VAA data from the account info
-    let posted_vaa_account_info = &ctx.accounts.posted_vaa;
-    let posted_vaa_data = posted_vaa_account_info.try_borrow_data()?;
-    // Use Vaa::read with Cursor
-    let mut cursor = Cursor::new(&posted_vaa_data[..]);
-    let vaa: Vaa = Vaa::read(&mut cursor)?;
-
-    // TODO: Add proper VAA verification logic here using wormhole_bridge data
-    // This typically involves checking the guardian signatures against the current guardian set
-    // stored in the wormhole_bridge account. This is crucial for security.
-    // Example placeholder:
-    // verify_vaa(&ctx.accounts.wormhole_bridge, &vaa)?;
-
-    // Optional: Check against replay using BridgeRequest account
-    /*
-    let bridge_request = &mut ctx.accounts.bridge_request;
-    require!(bridge_request.status == BridgeStatus::Pending, ErrorCode::VaaAlreadyProcessed);
-    */
-
-    // --- Payload Processing ---
-    // Extract the raw payload bytes by matching the PayloadKind enum
-    let payload: &[u8] = match &vaa.body.payload {
-        PayloadKind::Binary(bytes) => bytes.as_slice(), // Use Binary variant
-        _ => return err!(ErrorCode::UnsupportedPayloadKind), // Error for other variants
-    };
-    require!(!payload.is_empty(), ErrorCode::InvalidVaaPayload);
-
-    let operation_code = payload[0];
-    let specific_payload_data = &payload[1..];
-
-    msg!("VAA Details: Chain={}, Addr={}, Seq={}",
-        vaa.body.emitter_chain, // Access via vaa.body
-        hex::encode(vaa.body.emitter_address), // Access via vaa.body
-        vaa.body.sequence // Access via vaa.body
+// Event recording the outcome of a processed VAA
+pub fn vaa_processed_event(pool: Pubkey, vaa: &ParsedVaa, status: BridgeStatus) -> VaaProcessed {
+    VaaProcessed {
+        pool,
+        vaa_hash: vaa.hash,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        operation_code: vaa.operation_code,
+        status,
+    }
+}
+
+// Deserialize a posted VAA account and split its payload into operation code and data.
+// Only the core bridge can write a PostedVAA at the address derived from the body hash, so an account
+// passing the owner and address checks carries a VAA whose guardian signatures were verified.
+// The core bridge only posts VAAs of a guardian set that hasn't expired (the previous set stays valid for
+// its expiration window after a rotation); once posted, a VAA stays processable. `signature_set` is the
+// signature set the PostedVAA names, read for the index of the guardian set that signed.
+pub fn parse_posted_vaa(posted_vaa: &AccountInfo, signature_set: &AccountInfo) -> Result<ParsedVaa> {
+    require!(*posted_vaa.owner == wormhole::program::ID, ErrorCode::InvalidPostedVaa);
+
+    let posted_vaa_data = posted_vaa.try_borrow_data()?;
+    require!(posted_vaa_data.starts_with(POSTED_VAA_MAGIC), ErrorCode::InvalidPostedVaa);
+    let vaa = PostedVaaData::deserialize(&mut &posted_vaa_data[POSTED_VAA_MAGIC.len()..])
+        .map_err(|_| error!(ErrorCode::InvalidPostedVaa))?;
+
+    // Hash the body as Wormhole does to identify the VAA (big-endian fields, then the payload)
+    let hash = keccak::hashv(&[
+        &vaa.vaa_time.to_be_bytes(),
+        &vaa.nonce.to_be_bytes(),
+        &vaa.emitter_chain.to_be_bytes(),
+        &vaa.emitter_address,
+        &vaa.sequence.to_be_bytes(),
+        &[vaa.consistency_level],
+        &vaa.payload,
+    ])
+    .to_bytes();
+    let (expected_posted_vaa, _) = Pubkey::find_program_address(
+        &[b"PostedVAA".as_ref(), hash.as_ref()],
+        &wormhole::program::ID,
     );
-    msg!("Processing Operation Code: {}", operation_code);
+    require!(posted_vaa.key() == expected_posted_vaa, ErrorCode::InvalidPostedVaa);
 
-    // TODO: Add checks for emitter_chain and emitter_address if needed
-    // require!(vaa.body.emitter_chain == SUI_CHAIN_ID, ErrorCode::InvalidEmitterChain);
-    // require!(vaa.body.emitter_address == SUI_BRIDGE_ADDRESS, ErrorCode::InvalidEmitterAddress);
+    require!(signature_set.key().to_bytes() == vaa.signature_set, ErrorCode::InvalidSignatureSet);
+    require!(*signature_set.owner == wormhole::program::ID, ErrorCode::InvalidSignatureSet);
+    let signatures = SignatureSetData::deserialize(&mut &signature_set.try_borrow_data()?[..])
+        .map_err(|_| error!(ErrorCode::InvalidSignatureSet))?;
+    require!(signatures.hash == hash, ErrorCode::InvalidSignatureSet);
 
-    match operation_code {
-        0 => { // AddLiquidityCompletion
-            msg!("Processing Add Liquidity Completion...");
-            let completion_payload = AddLiquidityCompletionPayload::try_from_slice(specific_payload_data)
-                .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
-            msg!("Payload: {:?}", completion_payload);
+    require!(!vaa.payload.is_empty(), ErrorCode::InvalidVaaPayload);
 
-            require!(
-                ctx.accounts.recipient.key().to_bytes() == completion_payload.recipient_address,
-                ErrorCode::RecipientMismatch
-            );
-            require!(
-                ctx.accounts.pool.pool_id == completion_payload.original_pool_id,
-                ErrorCode::PoolIdMismatch
-            );
+    msg!("VAA Details: Chain={}, Addr={}, Seq={}, Guardian set={}",
+        vaa.emitter_chain,
+        hex::encode(vaa.emitter_address),
+        vaa.sequence,
+        signatures.guardian_set_index
+    );
+    msg!("Processing Operation Code: {}", vaa.payload[0]);
+
+    // The emitter is checked against the registered emitters in `enforce_sequence_order`
+
+    Ok(ParsedVaa {
+        hash,
+        guardian_set_index: signatures.guardian_set_index,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        operation_code: vaa.payload[0],
+        payload: vaa.payload[1..].to_vec(),
+    })
+}
 
-            mint_lp_tokens(
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.lp_mint.to_account_info(),
-                ctx.accounts.recipient_lp_token_account.to_account_info(),
-                ctx.accounts.pool_authority.to_account_info(),
-                ctx.accounts.pool.key(),
-                completion_payload.lp_amount_to_mint,
-                ctx.bumps.pool_authority,
-            )?;
-            msg!("Minted {} LP tokens to {}", completion_payload.lp_amount_to_mint, ctx.accounts.recipient.key());
+// Helper function recording a processed VAA in its BridgeRequest account (replay protection + audit trail)
+pub fn record_bridge_request(
+    bridge_request: &mut Account<BridgeRequest>,
+    vaa: &ParsedVaa,
+    vaa_hash: [u8; 32],
+    bump: u8,
+) -> Result<()> {
+    require!(vaa.hash == vaa_hash, ErrorCode::VaaHashMismatch);
+    require!(vaa.payload.len() < MAX_BRIDGE_PAYLOAD_SIZE, ErrorCode::InvalidVaaPayload);
+
+    let mut payload = Vec::with_capacity(vaa.payload.len() + 1);
+    payload.push(vaa.operation_code);
+    payload.extend_from_slice(&vaa.payload);
+
+    bridge_request.wormhole_sequence = vaa.sequence;
+    bridge_request.emitter_chain = vaa.emitter_chain;
+    bridge_request.emitter_address = vaa.emitter_address;
+    bridge_request.guardian_set_index = vaa.guardian_set_index;
+    bridge_request.status = BridgeStatus::Completed; // Any failure reverts the whole transaction
+    bridge_request.payload = payload;
+    bridge_request.created_at = Clock::get()?.unix_timestamp;
+    bridge_request.bump = bump;
+    Ok(())
+}
 
+// Helper function validating the token account a bridge completion delivers to.
+// With a target from the payload, that account must be a token account of `mint` owned by the recipient
+// (e.g. a vault PDA's account); otherwise it must be the recipient's ATA, which is created if missing.
+// Returns the rent the payer spent creating it (0 when it already existed).
+pub fn resolve_recipient_token_account<'info>(
+    target_token_account: Option<[u8; 32]>,
+    token_account: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    match target_token_account {
+        Some(target) => {
+            require!(token_account.key().to_bytes() == target, ErrorCode::InvalidRecipientTokenAccount);
+            require!(*token_account.owner == token::ID, ErrorCode::InvalidRecipientTokenAccount);
+            let account_data = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?;
+            require!(account_data.mint == mint.key(), ErrorCode::InvalidMint);
+            require!(account_data.owner == recipient.key(), ErrorCode::InvalidRecipientTokenAccount);
+            Ok(0)
         }
-        1 => { // RemoveLiquidityCompletion
-            msg!("Processing Remove Liquidity Completion...");
-            let completion_payload = RemoveLiquidityCompletionPayload::try_from_slice(specific_payload_data)
-                 .map_err(|_| error!(ErrorCode::InvalidVaaPayload))?;
-            msg!("Payload: {:?}", completion_payload);
-
-            require!(
-                ctx.accounts.recipient.key().to_bytes() == completion_payload.recipient_address,
-                ErrorCode::RecipientMismatch
-            );
+        None => {
             require!(
-                ctx.accounts.pool.pool_id == completion_payload.original_pool_id,
-                ErrorCode::PoolIdMismatch
+                token_account.key() == get_associated_token_address(&recipient.key(), &mint.key()),
+                ErrorCode::InvalidRecipientTokenAccount
             );
+            if token_account.data_is_empty() {
+                // Only create the ATA when it doesn't exist yet (works for off-curve/PDA owners too)
+                associated_token::create(CpiContext::new(
+                    associated_token_program.clone(),
+                    associated_token::Create {
+                        payer: payer.clone(),
+                        associated_token: token_account.clone(),
+                        authority: recipient.clone(),
+                        mint: mint.clone(),
+                        system_program: system_program.clone(),
+                        token_program: token_program.clone(),
+                    },
+                ))?;
+                return rent_for(TokenAccount::LEN);
+            }
+            Ok(0)
+        }
+    }
+}
 
-            transfer_pool_tokens(
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.token_a_account.to_account_info(),
-                ctx.accounts.recipient_token_a_account.to_account_info(),
-                ctx.accounts.pool_authority.to_account_info(),
-                ctx.accounts.pool.key(),
-                completion_payload.amount_a_to_transfer,
-                ctx.bumps.pool_authority,
-            )?;
-             msg!("Transferred {} Token A to {}", completion_payload.amount_a_to_transfer, ctx.accounts.recipient.key());
-
-             transfer_pool_tokens(
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.token_b_account.to_account_info(),
-                ctx.accounts.recipient_token_b_account.to_account_info(),
-                ctx.accounts.pool_authority.to_account_info(),
-                ctx.accounts.pool.key(),
-                completion_payload.amount_b_to_transfer,
-                ctx.bumps.pool_authority,
-            )?;
-            msg!("Transferred {} Token B to {}", completion_payload.amount_b_to_transfer, ctx.accounts.recipient.key());
+// Helper function rejecting unregistered emitters and enforcing in-order processing for emitters that require it.
+// `sequence_tracker` must be the tracker PDA of the VAA's emitter; an emitter is registered once the admin
+// created its tracker (set_emitter_ordering). For ordered emitters the VAA must carry `next_expected_sequence`,
+// which is then advanced.
+pub fn enforce_sequence_order<'info>(
+    sequence_tracker: &AccountInfo<'info>,
+    vaa: &ParsedVaa,
+) -> Result<()> {
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"sequence_tracker".as_ref(),
+            vaa.emitter_chain.to_le_bytes().as_ref(),
+            vaa.emitter_address.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require!(sequence_tracker.key() == expected_tracker, ErrorCode::InvalidSequenceTracker);
 
-        }
-        _ => {
-            msg!("Unknown operation code in payload: {}", operation_code);
-            return err!(ErrorCode::InvalidBridgeOperation);
-        }
+    if sequence_tracker.data_is_empty() {
+        msg!("Emitter {} on chain {} is not registered", hex::encode(vaa.emitter_address), vaa.emitter_chain);
+        return err!(ErrorCode::InvalidEmitterAddress);
+    }
+
+    let mut tracker: Account<SequenceTracker> = Account::try_from(sequence_tracker)?;
+    if !tracker.ordered {
+        return Ok(());
+    }
+
+    require!(vaa.sequence == tracker.next_expected_sequence, ErrorCode::SequenceOutOfOrder);
+    tracker.next_expected_sequence = tracker.next_expected_sequence
+        .checked_add(1)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    tracker.exit(&crate::ID)?;
+
+    msg!("In-order sequence {} accepted", vaa.sequence);
+    Ok(())
+}
+
+// Rent-exempt minimum of an account of `space` bytes, i.e. what its payer spent creating it
+pub fn rent_for(space: usize) -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(space))
+}
+
+// Helper function reimbursing the VAA submitter for `rent_paid`, the rent of the accounts it created
+// in this call (BridgeRequest record, recipient ATAs, ...), capped by the configured per-VAA maximum.
+// Funded by lamports deposited into the config account above its rent-exempt minimum;
+// an empty fund skips the reimbursement instead of failing the bridge operation.
+pub fn reimburse_relayer_rent<'info>(
+    config: &Account<'info, Config>,
+    relayer: &AccountInfo<'info>,
+    rent_paid: u64,
+) -> Result<()> {
+    if config.rent_reimbursement_lamports == 0 || rent_paid == 0 {
+        return Ok(());
     }
 
-    msg!("VAA processed successfully.");
+    let config_info = config.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(config_info.data_len());
+    let available = config_info.lamports().saturating_sub(rent_exempt_minimum);
+    let amount = std::cmp::min(std::cmp::min(rent_paid, config.rent_reimbursement_lamports), available);
+
+    if amount == 0 {
+        msg!("Relayer fund is empty, skipping rent reimbursement");
+        return Ok(());
+    }
+
+    **config_info.try_borrow_mut_lamports()? -= amount;
+    **relayer.try_borrow_mut_lamports()? += amount;
+    msg!("Reimbursed {} lamports of rent to {}", amount, relayer.key());
+
     Ok(())
 }
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, Burn};
-use crate::state::Pool;
+use crate::state::{Pool, PoolLedger};
 use crate::errors::ErrorCode; // Assuming errors.rs exists
+use crate::math;
+use crate::instructions::add_liquidity::check_deadline;
+use crate::events::LiquidityRemoved;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
     // User removing liquidity
@@ -25,6 +29,14 @@ pub struct RemoveLiquidity<'info> {
     )]
     pub pool_authority: AccountInfo<'info>,
 
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
     // Pool's token accounts (funds transferred from here)
     #[account(
         mut,
@@ -86,31 +98,43 @@ pub fn handler(
     liquidity_amount: u64, // Amount of LP tokens to burn
     amount_a_min: u64,     // Minimum amount of token A user expects back
     amount_b_min: u64,     // Minimum amount of token B user expects back
+    deadline: i64,         // Unix timestamp after which the transaction is rejected
 ) -> Result<()> {
     msg!("Removing liquidity...");
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
+    check_deadline(&clock, deadline)?;
 
     // Check if pool is active
     require!(pool.status == 0, ErrorCode::PoolPaused);
     require!(liquidity_amount > 0, ErrorCode::ZeroLiquidityBurned);
 
-    let reserve_a = ctx.accounts.token_a_account.amount;
-    let reserve_b = ctx.accounts.token_b_account.amount;
-    let lp_supply = ctx.accounts.lp_mint.supply;
+    // Quote against the combined Solana + Sui reserves and LP supply
+    let ledger = &mut ctx.accounts.ledger;
+    let reserve_a = ledger.total_reserve_a()?;
+    let reserve_b = ledger.total_reserve_b()?;
+    let lp_supply = ledger.total_lp_supply()?;
 
     require!(lp_supply > 0, ErrorCode::PoolEmpty); // Cannot remove from empty pool
     require!(liquidity_amount <= ctx.accounts.user_lp_token_account.amount, ErrorCode::InsufficientLpTokens);
 
-    // Calculate the amount of token A and B to return
+    // Calculate the amount of token A and B to return (rounded down)
     // amount = (liquidity_to_burn / total_lp_supply) * reserve
-    let amount_a_out = liquidity_amount.checked_mul(reserve_a).unwrap().checked_div(lp_supply).unwrap();
-    let amount_b_out = liquidity_amount.checked_mul(reserve_b).unwrap().checked_div(lp_supply).unwrap();
+    let amount_a_out = math::share_of_reserve(liquidity_amount, reserve_a, lp_supply)?;
+    let amount_b_out = math::share_of_reserve(liquidity_amount, reserve_b, lp_supply)?;
 
     // Check against minimum amounts (slippage protection)
     require!(amount_a_out >= amount_a_min, ErrorCode::SlippageExceeded);
     require!(amount_b_out >= amount_b_min, ErrorCode::SlippageExceeded);
 
+    // The share is paid out of the Solana vaults only (accrued protocol fees excluded)
+    let (local_reserve_a, local_reserve_b) = pool.lp_reserves(
+        ctx.accounts.token_a_account.amount,
+        ctx.accounts.token_b_account.amount,
+    );
+    require!(amount_a_out <= local_reserve_a, ErrorCode::InsufficientLocalReserves);
+    require!(amount_b_out <= local_reserve_b, ErrorCode::InsufficientLocalReserves);
+
     // Transfer tokens from pool to user
     transfer_pool_tokens(
         ctx.accounts.token_program.to_account_info(),
@@ -142,11 +166,20 @@ pub fn handler(
     )?;
 
     // Update pool state
-    pool.total_liquidity = pool.total_liquidity.checked_sub(liquidity_amount).unwrap();
+    pool.total_liquidity = math::checked_sub(pool.total_liquidity, liquidity_amount)?;
     pool.last_updated_at = clock.unix_timestamp;
+    ledger.sub_local(amount_a_out, amount_b_out, liquidity_amount)?;
 
     msg!("Liquidity removed: A={}, B={}, LP={}", amount_a_out, amount_b_out, liquidity_amount);
 
+    emit_cpi!(LiquidityRemoved {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        amount_a: amount_a_out,
+        amount_b: amount_b_out,
+        lp_tokens_burned: liquidity_amount,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{Pool, PoolLedger, TokenSide};
+use crate::errors::ErrorCode;
+use crate::math;
+use crate::events::{self, LiquidityRemoved};
+use crate::instructions::add_liquidity::check_deadline;
+use crate::instructions::remove_liquidity::{transfer_pool_tokens, burn_lp_tokens};
+use crate::instructions::swap::settle_local_swap_leg;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLiquiditySingleSided<'info> {
+    // User removing liquidity
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool state account
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Pool authority PDA
+    /// CHECK: Authority PDA, seeds checked below. Used as signer.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    // Pool's token accounts
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    // User's account of the withdrawn token (mint checked against `side` in handler)
+    #[account(
+        mut,
+        constraint = user_token_out.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_token_out: Account<'info, TokenAccount>,
+
+    // LP token mint
+    #[account(
+        mut, // Supply will decrease
+        seeds = [b"lp_mint".as_ref(), pool.key().as_ref()],
+        bump = pool.lp_mint_bump,
+        constraint = lp_mint.key() == pool.lp_mint @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    // User's LP token account (LP tokens burned from here)
+    #[account(
+        mut,
+        constraint = user_lp_token_account.mint == lp_mint.key() @ ErrorCode::InvalidMint,
+        constraint = user_lp_token_account.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_lp_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Handler function withdrawing into a single token: burns LP for the proportional share of both
+// reserves, swaps the unwanted leg through the curve (paying the pool fee) and pays out one token
+pub fn handler(
+    ctx: Context<RemoveLiquiditySingleSided>,
+    lp_amount: u64,      // Amount of LP tokens to burn
+    min_out: u64,        // Minimum amount of the withdrawn token the user accepts
+    side: TokenSide,     // Token to withdraw
+    deadline: i64,       // Unix timestamp after which the transaction is rejected
+) -> Result<()> {
+    msg!("Removing single-sided liquidity...");
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+    check_deadline(&clock, deadline)?;
+
+    require!(pool.status == 0, ErrorCode::PoolPaused);
+    require!(lp_amount > 0, ErrorCode::ZeroLiquidityBurned);
+    require!(lp_amount <= ctx.accounts.user_lp_token_account.amount, ErrorCode::InsufficientLpTokens);
+
+    let (mint_out, pool_source) = match side {
+        TokenSide::A => (pool.token_a_mint, &ctx.accounts.token_a_account),
+        TokenSide::B => (pool.token_b_mint, &ctx.accounts.token_b_account),
+    };
+    require!(ctx.accounts.user_token_out.mint == mint_out, ErrorCode::InvalidMint);
+
+    // Proportional share of the combined Solana + Sui reserves (rounded down); the unwanted share is
+    // swapped back into the pool, priced against the Solana vaults after removal
+    let ledger = &mut ctx.accounts.ledger;
+    let quote = math::quote_remove_liquidity_single_sided(
+        lp_amount,
+        side == TokenSide::A,
+        (ledger.total_reserve_a()?, ledger.total_reserve_b()?),
+        ledger.total_lp_supply()?,
+        pool.lp_reserves(ctx.accounts.token_a_account.amount, ctx.accounts.token_b_account.amount),
+        pool.fee_percentage,
+    )?;
+    let (kept_share, swapped_share, swap_out, protocol_fee, amount_out) =
+        (quote.kept_share, quote.swapped_share, quote.swap_out, quote.protocol_fee, quote.amount_out);
+    // The swapped share is the input of the internal swap: withdrawing A sells B
+    let swap_a_to_b = side == TokenSide::B;
+    require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+    transfer_pool_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        pool_source.to_account_info(),
+        ctx.accounts.user_token_out.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        pool.key(),
+        amount_out,
+        ctx.bumps.pool_authority,
+    )?;
+
+    burn_lp_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_lp_token_account.to_account_info(),
+        ctx.accounts.lp_mint.to_account_info(),
+        ctx.accounts.user.to_account_info(), // User authorizes burning their own tokens
+        lp_amount,
+    )?;
+
+    // Both shares leave the reserves with the burned LP, then the swapped share comes back through the
+    // internal swap, settled like `swap`: only the withdrawn token leaves the pool
+    let (share_a, share_b) = match side {
+        TokenSide::A => (kept_share, swapped_share),
+        TokenSide::B => (swapped_share, kept_share),
+    };
+    ledger.sub_local(share_a, share_b, lp_amount)?;
+    let swap = math::SwapQuote { amount_out: swap_out, protocol_fee };
+    settle_local_swap_leg(pool, ledger, swapped_share, swap_a_to_b, &swap)?;
+    pool.total_liquidity = math::checked_sub(pool.total_liquidity, lp_amount)?;
+    pool.last_updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Single-sided liquidity removed: LP={}, kept={}, swapped {} for {}, out={}",
+        lp_amount, kept_share, swapped_share, swap_out, amount_out
+    );
+
+    if swapped_share > 0 {
+        emit_cpi!(events::Swap {
+            pool: pool.key(),
+            user: ctx.accounts.user.key(),
+            a_to_b: swap_a_to_b,
+            amount_in: swapped_share,
+            amount_out: swap_out,
+            protocol_fee,
+        });
+    }
+
+    let (amount_a, amount_b) = match side {
+        TokenSide::A => (amount_out, 0),
+        TokenSide::B => (0, amount_out),
+    };
+    emit_cpi!(LiquidityRemoved {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        amount_a,
+        amount_b,
+        lp_tokens_burned: lp_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, SequenceTracker};
+use crate::errors::ErrorCode;
+use crate::events::EmitterOrderingSet;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(emitter_chain: u16, emitter_address: [u8; 32])]
+pub struct SetEmitterOrdering<'info> {
+    // Config admin (pays for the tracker on first use)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+
+    // Per-emitter sequence tracker (PDA)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = SequenceTracker::SIZE,
+        seeds = [b"sequence_tracker".as_ref(), emitter_chain.to_le_bytes().as_ref(), emitter_address.as_ref()],
+        bump
+    )]
+    pub sequence_tracker: Account<'info, SequenceTracker>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Handler function registering an emitter and enabling/disabling in-order processing for it
+pub fn handler(
+    ctx: Context<SetEmitterOrdering>,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    ordered: bool,                  // Enforce strict sequence order for this emitter
+    next_expected_sequence: u64,    // Sequence the next processed VAA must carry
+) -> Result<()> {
+    let tracker = &mut ctx.accounts.sequence_tracker;
+    tracker.emitter_chain = emitter_chain;
+    tracker.emitter_address = emitter_address;
+    tracker.ordered = ordered;
+    tracker.next_expected_sequence = next_expected_sequence;
+    tracker.bump = ctx.bumps.sequence_tracker;
+
+    msg!("Emitter ordering for chain {}: ordered={}, next sequence={}", emitter_chain, ordered, next_expected_sequence);
+
+    emit_cpi!(EmitterOrderingSet {
+        admin: ctx.accounts.admin.key(),
+        emitter_chain,
+        emitter_address,
+        ordered,
+        next_expected_sequence,
+    });
+
+    Ok(())
+}
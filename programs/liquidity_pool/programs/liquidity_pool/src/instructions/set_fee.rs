@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, Config};
+use crate::errors::ErrorCode;
+use crate::events::FeeChanged;
+use crate::math::FEE_DENOMINATOR;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+// Handler function changing the swap fee of a pool
+pub fn handler(
+    ctx: Context<SetFee>,
+    fee_percentage: u64, // New fee in basis points
+) -> Result<()> {
+    require!(fee_percentage < FEE_DENOMINATOR, ErrorCode::InvalidFee);
+
+    let pool = &mut ctx.accounts.pool;
+    let old_fee = pool.fee_percentage;
+    pool.fee_percentage = fee_percentage;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pool fee changed from {} to {} bps", old_fee, fee_percentage);
+
+    emit_cpi!(FeeChanged {
+        pool: pool.key(),
+        admin: ctx.accounts.admin.key(),
+        old_fee,
+        new_fee: fee_percentage,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, Config};
+use crate::errors::ErrorCode;
+use crate::events::StatusChanged;
+
+// Highest valid status value (0: active, 1: paused, 2: deprecated)
+pub const MAX_POOL_STATUS: u8 = 2;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolStatus<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+// Handler function pausing, unpausing or deprecating a pool
+pub fn handler(
+    ctx: Context<SetPoolStatus>,
+    status: u8, // New pool status
+) -> Result<()> {
+    require!(status <= MAX_POOL_STATUS, ErrorCode::InvalidPoolStatus);
+
+    let pool = &mut ctx.accounts.pool;
+    let old_status = pool.status;
+    pool.status = status;
+    pool.last_updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pool status changed from {} to {}", old_status, status);
+
+    emit_cpi!(StatusChanged {
+        pool: pool.key(),
+        admin: ctx.accounts.admin.key(),
+        old_status,
+        new_status: status,
+    });
+
+    Ok(())
+}
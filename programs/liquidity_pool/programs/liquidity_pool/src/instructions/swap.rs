@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Pool, PoolLedger};
+use crate::errors::ErrorCode;
+use crate::math;
+use crate::events;
+use crate::instructions::add_liquidity::{transfer_tokens, check_deadline};
+use crate::instructions::remove_liquidity::transfer_pool_tokens;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    // User swapping tokens
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool state account
+    #[account(
+        mut,
+        seeds = [b"pool".as_ref(), pool.token_a_mint.key().as_ref(), pool.token_b_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Pool authority PDA
+    /// CHECK: Authority PDA, seeds checked below. Used as signer.
+    #[account(
+        seeds = [b"authority".as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    // Global LP ledger of the pool
+    #[account(
+        mut,
+        seeds = [b"ledger".as_ref(), pool.pool_id.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, PoolLedger>,
+
+    // Pool's token accounts
+    #[account(
+        mut,
+        seeds = [b"token_a".as_ref(), pool.key().as_ref()],
+        bump = pool.token_a_bump,
+        constraint = token_a_account.key() == pool.token_a_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_a_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_b".as_ref(), pool.key().as_ref()],
+        bump = pool.token_b_bump,
+        constraint = token_b_account.key() == pool.token_b_account @ ErrorCode::InvalidPoolTokenAccount
+    )]
+    pub token_b_account: Account<'info, TokenAccount>,
+
+    // User's token accounts
+    #[account(
+        mut,
+        constraint = user_token_a.mint == pool.token_a_mint @ ErrorCode::InvalidMint,
+        constraint = user_token_a.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_b.mint == pool.token_b_mint @ ErrorCode::InvalidMint,
+        constraint = user_token_b.owner == user.key() @ ErrorCode::InvalidOwner
+    )]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Handler function for swapping token A for token B (or B for A)
+pub fn handler(
+    ctx: Context<Swap>,
+    amount_in: u64,       // Amount of the input token
+    min_amount_out: u64,  // Minimum amount of the output token the user accepts
+    a_to_b: bool,         // true: sell token A for token B, false: sell token B for token A
+    deadline: i64,        // Unix timestamp after which the transaction is rejected
+) -> Result<()> {
+    msg!("Swapping...");
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+    check_deadline(&clock, deadline)?;
+
+    require!(pool.status == 0, ErrorCode::PoolPaused);
+    require!(amount_in > 0, ErrorCode::ZeroSwapAmount);
+
+    let quote = quote_swap_leg(pool, ctx.accounts.token_a_account.amount, ctx.accounts.token_b_account.amount, amount_in, a_to_b)?;
+    let (amount_out, protocol_fee) = (quote.amount_out, quote.protocol_fee);
+    require!(amount_out >= min_amount_out, ErrorCode::SlippageExceeded);
+
+    let (user_source, pool_destination, pool_source, user_destination) = if a_to_b {
+        (&ctx.accounts.user_token_a, &ctx.accounts.token_a_account, &ctx.accounts.token_b_account, &ctx.accounts.user_token_b)
+    } else {
+        (&ctx.accounts.user_token_b, &ctx.accounts.token_b_account, &ctx.accounts.token_a_account, &ctx.accounts.user_token_a)
+    };
+
+    // Transfer input tokens from user to pool
+    transfer_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        user_source.to_account_info(),
+        pool_destination.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount_in,
+    )?;
+
+    // Transfer output tokens from pool to user
+    transfer_pool_tokens(
+        ctx.accounts.token_program.to_account_info(),
+        pool_source.to_account_info(),
+        user_destination.to_account_info(),
+        ctx.accounts.pool_authority.to_account_info(),
+        pool.key(),
+        amount_out,
+        ctx.bumps.pool_authority,
+    )?;
+
+    settle_local_swap_leg(pool, &mut ctx.accounts.ledger, amount_in, a_to_b, &quote)?;
+    pool.last_updated_at = clock.unix_timestamp;
+
+    msg!("Swapped: in={}, out={}, a_to_b={}", amount_in, amount_out, a_to_b);
+
+    emit_cpi!(events::Swap {
+        pool: pool.key(),
+        user: ctx.accounts.user.key(),
+        a_to_b,
+        amount_in,
+        amount_out,
+        protocol_fee,
+    });
+
+    Ok(())
+}
+
+// Helper function pricing a swap leg against the LP-owned part of the Solana vaults (accrued protocol
+// fees excluded). Every instruction swapping against the pool prices through it.
+pub fn quote_swap_leg(pool: &Pool, vault_a: u64, vault_b: u64, amount_in: u64, a_to_b: bool) -> Result<math::SwapQuote> {
+    math::quote_swap(amount_in, a_to_b, pool.lp_reserves(vault_a, vault_b), pool.fee_percentage)
+}
+
+// Helper function settling a swap leg whose input was paid into the Solana vault. The protocol fee
+// stays in the vault but is not part of the LP reserves; the rest of the input joins them.
+pub fn settle_local_swap_leg(
+    pool: &mut Pool,
+    ledger: &mut PoolLedger,
+    amount_in: u64,
+    a_to_b: bool,
+    quote: &math::SwapQuote,
+) -> Result<()> {
+    pool.accrue_protocol_fee(a_to_b, quote.protocol_fee)?;
+    let amount_in_to_reserves = math::checked_sub(amount_in, quote.protocol_fee)?;
+    if a_to_b {
+        ledger.add_local(amount_in_to_reserves, 0, 0)?;
+        ledger.sub_local(0, quote.amount_out, 0)
+    } else {
+        ledger.add_local(0, amount_in_to_reserves, 0)?;
+        ledger.sub_local(quote.amount_out, 0, 0)
+    }
+}
+
+// Helper function settling a swap leg whose input stayed in the Sui pool (cross-chain swap intent).
+// The whole input joins the Sui reserves and the protocol fee is set aside in the input token's Solana
+// vault instead, so the combined reserves move exactly as with a local swap.
+pub fn settle_remote_swap_leg(
+    pool: &mut Pool,
+    ledger: &mut PoolLedger,
+    amount_in: u64,
+    a_to_b: bool,
+    quote: &math::SwapQuote,
+) -> Result<()> {
+    pool.accrue_protocol_fee(a_to_b, quote.protocol_fee)?;
+    if a_to_b {
+        ledger.add_remote(amount_in, 0, 0)?;
+        ledger.sub_local(quote.protocol_fee, quote.amount_out, 0)
+    } else {
+        ledger.add_remote(0, amount_in, 0)?;
+        ledger.sub_local(quote.amount_out, quote.protocol_fee, 0)
+    }
+}
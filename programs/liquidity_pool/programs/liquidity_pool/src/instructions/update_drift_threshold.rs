@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::Config;
+use crate::errors::ErrorCode;
+use crate::events::DriftThresholdUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDriftThreshold<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+}
+
+// Handler function for updating the price drift alarm threshold
+pub fn handler(
+    ctx: Context<UpdateDriftThreshold>,
+    max_price_drift_bps: u64, // Divergence (basis points) that raises the drift alarm
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_max_price_drift_bps = config.max_price_drift_bps;
+    config.max_price_drift_bps = max_price_drift_bps;
+
+    msg!("Drift alarm threshold set to {} bps", max_price_drift_bps);
+
+    emit_cpi!(DriftThresholdUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_max_price_drift_bps,
+        new_max_price_drift_bps: max_price_drift_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::Config;
+use crate::errors::ErrorCode;
+use crate::events::RelayerSettingsUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRelayerSettings<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account (also holds the lamports used for rent reimbursement)
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+}
+
+// Handler function for updating the relayer rent reimbursement policy
+pub fn handler(
+    ctx: Context<UpdateRelayerSettings>,
+    rent_reimbursement_lamports: u64, // Max rent lamports reimbursed per processed VAA (0 disables)
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_rent_reimbursement_lamports = config.rent_reimbursement_lamports;
+    config.rent_reimbursement_lamports = rent_reimbursement_lamports;
+
+    msg!("Rent reimbursement capped at {} lamports per VAA", rent_reimbursement_lamports);
+
+    emit_cpi!(RelayerSettingsUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_rent_reimbursement_lamports,
+        new_rent_reimbursement_lamports: rent_reimbursement_lamports,
+    });
+
+    Ok(())
+}
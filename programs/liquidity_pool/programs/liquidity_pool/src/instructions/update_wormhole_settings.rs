@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, OperationConsistency};
+use crate::errors::ErrorCode;
+use crate::events::WormholeSettingsUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateWormholeSettings<'info> {
+    // Config admin
+    pub admin: Signer<'info>,
+
+    // Program config account
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAuthority
+    )]
+    pub config: Account<'info, Config>,
+}
+
+// Handler function for updating the outbound Wormhole message settings
+pub fn handler(
+    ctx: Context<UpdateWormholeSettings>,
+    batch_nonce: Option<u32>,                  // New batch nonce (None keeps the current one)
+    consistency_levels: Vec<OperationConsistency>, // Per-operation consistency overrides
+) -> Result<()> {
+    msg!("Updating Wormhole settings...");
+    let config = &mut ctx.accounts.config;
+
+    if let Some(nonce) = batch_nonce {
+        config.batch_nonce = nonce;
+        msg!("Batch nonce set to {}", nonce);
+    }
+
+    for entry in consistency_levels {
+        config.consistency_levels[entry.operation as usize] = entry.level;
+        msg!("Consistency for {:?} set to {:?}", entry.operation, entry.level);
+    }
+
+    emit_cpi!(WormholeSettingsUpdated {
+        admin: ctx.accounts.admin.key(),
+        batch_nonce: config.batch_nonce,
+        consistency_levels: config.consistency_levels,
+    });
+
+    Ok(())
+}
@@ -6,6 +6,10 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod payloads; // Declare payloads module
+pub mod outbound; // Helpers for posting Wormhole messages
+pub mod receiver; // Callback interface for receiver programs of bridge completions
+pub mod math; // Overflow-safe u128 quote math shared by all handlers
+pub mod events; // Anchor events emitted by the handlers
 
 // Import modules created earlier (relative path from this file's perspective)
 // Note: Anchor build might handle paths differently, but typically modules are declared relative to lib.rs
@@ -23,7 +27,7 @@ declare_id!("GL6uWvwZAapbf54GQb7PwKxXrC6gnjyNcrBMeAvkh7mg");
 pub mod liquidity_pool_program { // Renamed module to avoid conflict with crate name
     use super::*; // Make items from outer scope available
 
-    // Instruction: Create a new liquidity pool
+    // Instruction: Create a new liquidity pool (admin only)
     pub fn create_pool(
         ctx: Context<CreatePool>,
         fee_percentage: u64,
@@ -32,20 +36,23 @@ pub mod liquidity_pool_program { // Renamed module to avoid conflict with crate
         instructions::create_pool::handler(ctx, fee_percentage, pool_id)
     }
 
-    // Instruction: Add liquidity to an existing pool
+    // Instruction: Add liquidity to an existing pool.
+    // Returns the amounts actually deposited and the LP minted (Solana return data).
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
         amount_a_desired: u64,
         amount_b_desired: u64,
         amount_a_min: u64,
-        amount_b_min: u64
-    ) -> Result<()> {
+        amount_b_min: u64,
+        deadline: i64 // Unix timestamp after which the transaction is rejected
+    ) -> Result<AddLiquidityOutcome> {
         instructions::add_liquidity::handler(
             ctx,
             amount_a_desired,
             amount_b_desired,
             amount_a_min,
-            amount_b_min
+            amount_b_min,
+            deadline
         )
     }
 
@@ -54,44 +61,168 @@ pub mod liquidity_pool_program { // Renamed module to avoid conflict with crate
         ctx: Context<RemoveLiquidity>,
         liquidity_amount: u64, // Amount of LP tokens to burn
         amount_a_min: u64,
-        amount_b_min: u64
+        amount_b_min: u64,
+        deadline: i64 // Unix timestamp after which the transaction is rejected
     ) -> Result<()> {
         instructions::remove_liquidity::handler(
             ctx,
             liquidity_amount,
             amount_a_min,
-            amount_b_min
+            amount_b_min,
+            deadline
         )
     }
 
-    // Instruction: Process a Wormhole VAA
-    pub fn process_vaa(
-        ctx: Context<ProcessVAA>,
+    // Instruction: Swap token A for token B (or B for A)
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+        deadline: i64 // Unix timestamp after which the transaction is rejected
+    ) -> Result<()> {
+        instructions::swap::handler(ctx, amount_in, min_amount_out, a_to_b, deadline)
+    }
+
+    // Instruction: Add liquidity with a single token (part of it is swapped to balance the deposit)
+    pub fn add_liquidity_single_sided(
+        ctx: Context<AddLiquiditySingleSided>,
+        amount_in: u64,
+        min_lp_out: u64,
+        side: TokenSide,
+        deadline: i64 // Unix timestamp after which the transaction is rejected
+    ) -> Result<()> {
+        instructions::add_liquidity_single_sided::handler(ctx, amount_in, min_lp_out, side, deadline)
+    }
+
+    // Instruction: Remove liquidity into a single token (the other share is swapped through the pool)
+    pub fn remove_liquidity_single_sided(
+        ctx: Context<RemoveLiquiditySingleSided>,
+        lp_amount: u64,
+        min_out: u64,
+        side: TokenSide,
+        deadline: i64 // Unix timestamp after which the transaction is rejected
+    ) -> Result<()> {
+        instructions::remove_liquidity_single_sided::handler(ctx, lp_amount, min_out, side, deadline)
+    }
+
+    // Instruction: Process a Wormhole VAA completing an AddLiquidity operation from Sui
+    pub fn process_add_liquidity_vaa<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessAddLiquidityVAA<'info>>,
         vaa_hash: [u8; 32] // Identifier for the VAA to process
     ) -> Result<()> {
-        instructions::process_vaa::handler(ctx, vaa_hash)
+        instructions::process_add_liquidity_vaa::handler(ctx, vaa_hash)
     }
 
-    // TODO: Add other instructions as needed (e.g., swap, update_fees, etc.)
-}
+    // Instruction: Process a Wormhole VAA completing a RemoveLiquidity operation from Sui
+    pub fn process_remove_liquidity_vaa<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessRemoveLiquidityVAA<'info>>,
+        vaa_hash: [u8; 32] // Identifier for the VAA to process
+    ) -> Result<()> {
+        instructions::process_remove_liquidity_vaa::handler(ctx, vaa_hash)
+    }
 
-// Optional: Define events if needed using #[event] macro
-/*
-#[event]
-pub struct LiquidityAdded {
-    pool: Pubkey,
-    user: Pubkey,
-    amount_a: u64,
-    amount_b: u64,
-    lp_tokens_minted: u64,
-}
+    // Instruction: Process a Wormhole VAA carrying a swap intent from Sui (refunded on Sui if it can't be met)
+    pub fn process_swap_vaa(
+        ctx: Context<ProcessSwapVAA>,
+        vaa_hash: [u8; 32] // Identifier for the VAA to process
+    ) -> Result<()> {
+        instructions::process_swap_vaa::handler(ctx, vaa_hash)
+    }
+
+    // Instruction: Process a Wormhole VAA carrying a reserve snapshot of the mirrored Sui pool
+    pub fn process_reserve_snapshot_vaa(
+        ctx: Context<ProcessReserveSnapshotVAA>,
+        vaa_hash: [u8; 32] // Identifier for the VAA to process
+    ) -> Result<()> {
+        instructions::process_reserve_snapshot_vaa::handler(ctx, vaa_hash)
+    }
+
+    // Instruction: Burn LP tokens and post a message minting the same LP position on Sui
+    pub fn bridge_lp_out(
+        ctx: Context<BridgeLpOut>,
+        lp_amount: u64,
+        sui_recipient: [u8; 32],
+        deadline: i64 // Unix timestamp after which the transaction is rejected
+    ) -> Result<()> {
+        instructions::bridge_lp_out::handler(ctx, lp_amount, sui_recipient, deadline)
+    }
+
+    // Instruction: Process a Wormhole VAA carrying an LP position migrated from Sui
+    pub fn process_lp_migration_vaa(
+        ctx: Context<ProcessLpMigrationVAA>,
+        vaa_hash: [u8; 32] // Identifier for the VAA to process
+    ) -> Result<()> {
+        instructions::process_lp_migration_vaa::handler(ctx, vaa_hash)
+    }
+
+    // Instruction: Initialize the program config (caller becomes admin)
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    // Instruction: Update outbound Wormhole message settings (admin only)
+    pub fn update_wormhole_settings(
+        ctx: Context<UpdateWormholeSettings>,
+        batch_nonce: Option<u32>,
+        consistency_levels: Vec<OperationConsistency>
+    ) -> Result<()> {
+        instructions::update_wormhole_settings::handler(ctx, batch_nonce, consistency_levels)
+    }
+
+    // Instruction: Update the relayer rent reimbursement policy (admin only)
+    pub fn update_relayer_settings(
+        ctx: Context<UpdateRelayerSettings>,
+        rent_reimbursement_lamports: u64
+    ) -> Result<()> {
+        instructions::update_relayer_settings::handler(ctx, rent_reimbursement_lamports)
+    }
+
+    // Instruction: Register an emitter and enable or disable in-order VAA processing for it (admin only)
+    pub fn set_emitter_ordering(
+        ctx: Context<SetEmitterOrdering>,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        ordered: bool,
+        next_expected_sequence: u64
+    ) -> Result<()> {
+        instructions::set_emitter_ordering::handler(
+            ctx,
+            emitter_chain,
+            emitter_address,
+            ordered,
+            next_expected_sequence
+        )
+    }
+
+    // Instruction: Update the cross-chain price drift alarm threshold (admin only)
+    pub fn update_drift_threshold(
+        ctx: Context<UpdateDriftThreshold>,
+        max_price_drift_bps: u64
+    ) -> Result<()> {
+        instructions::update_drift_threshold::handler(ctx, max_price_drift_bps)
+    }
+
+    // Instruction: Pause, unpause or deprecate a pool (admin only)
+    pub fn set_pool_status(
+        ctx: Context<SetPoolStatus>,
+        status: u8
+    ) -> Result<()> {
+        instructions::set_pool_status::handler(ctx, status)
+    }
+
+    // Instruction: Transfer the accrued protocol fees out of the pool (admin only)
+    pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
+        instructions::collect_fees::handler(ctx)
+    }
+
+    // Instruction: Change the swap fee of a pool (admin only)
+    pub fn set_fee(
+        ctx: Context<SetFee>,
+        fee_percentage: u64 // Basis points
+    ) -> Result<()> {
+        instructions::set_fee::handler(ctx, fee_percentage)
+    }
 
-#[event]
-pub struct LiquidityRemoved {
-    pool: Pubkey,
-    user: Pubkey,
-    amount_a: u64,
-    amount_b: u64,
-    lp_tokens_burned: u64,
+    // TODO: Add other instructions as needed
 }
-*/
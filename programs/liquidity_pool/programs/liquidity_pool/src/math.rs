@@ -0,0 +1,427 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Fees are expressed in basis points of this denominator (30 = 0.3%)
+pub const FEE_DENOMINATOR: u64 = 10_000;
+
+// Share of each swap fee accrued to the protocol (1/6, as in Uniswap v2); the rest stays with LPs
+pub const PROTOCOL_FEE_SHARE_DENOMINATOR: u64 = 6;
+
+// LP tokens permanently locked by the first deposit (as in Uniswap v2). Makes the initial
+// LP share too expensive to inflate and keeps the LP supply from ever returning to zero.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+// Rounding direction of a division. Every quote picks the direction that favours the pool:
+// amounts paid out and LP minted round down, amounts the pool receives round up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+// a + b, failing with Overflow
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or(error!(ErrorCode::Overflow))
+}
+
+// a - b, failing with Underflow
+pub fn checked_sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b).ok_or(error!(ErrorCode::Underflow))
+}
+
+// a * b / denominator with a u128 intermediate, rounded in the given direction.
+// Fails with DivisionByZero for a zero denominator and Overflow if the result exceeds u64.
+pub fn mul_div(a: u64, b: u64, denominator: u64, rounding: Rounding) -> Result<u64> {
+    require!(denominator > 0, ErrorCode::DivisionByZero);
+
+    // u64 * u64 always fits in u128
+    let product = (a as u128) * (b as u128);
+    let denominator = denominator as u128;
+    let mut result = product / denominator;
+    if rounding == Rounding::Up && product % denominator != 0 {
+        result += 1;
+    }
+
+    u64::try_from(result).map_err(|_| error!(ErrorCode::Overflow))
+}
+
+// floor(sqrt(value)) with Newton's method
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x / 2 + 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// Amount of the other token required to deposit `amount` at the current reserve ratio.
+// Rounded up: the depositor never pays less than their share is worth.
+pub fn quote_paired_amount(amount: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    mul_div(amount, reserve_out, reserve_in, Rounding::Up)
+}
+
+// LP tokens for the first deposit into an empty pool: floor(sqrt(amount_a * amount_b))
+pub fn initial_liquidity(amount_a: u64, amount_b: u64) -> Result<u64> {
+    let product = (amount_a as u128) * (amount_b as u128);
+    u64::try_from(integer_sqrt(product)).map_err(|_| error!(ErrorCode::Overflow))
+}
+
+// LP tokens for a deposit into a funded pool, proportional to the smaller side. Rounded down.
+pub fn liquidity_for_deposit(
+    amount_a: u64,
+    amount_b: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+) -> Result<u64> {
+    Ok(std::cmp::min(
+        mul_div(amount_a, lp_supply, reserve_a, Rounding::Down)?,
+        mul_div(amount_b, lp_supply, reserve_b, Rounding::Down)?,
+    ))
+}
+
+// Result of quoting a two-sided deposit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityQuote {
+    pub amount_a: u64,          // Token A taken from the depositor
+    pub amount_b: u64,          // Token B taken from the depositor
+    pub liquidity: u64,         // LP tokens minted to the depositor
+    pub locked_liquidity: u64,  // LP tokens locked in the dead account (first deposit only)
+}
+
+// Quote a deposit of up to (amount_a_desired, amount_b_desired) at the current reserves.
+// - Empty pool (no LP): takes both amounts as-is and mints sqrt(a * b), locking MINIMUM_LIQUIDITY.
+// - Drained pool (LP outstanding but a reserve is zero, e.g. after a cross-chain withdrawal
+//   released everything): the ratio is undefined and any deposit would be mispriced against
+//   the existing LP holders, so this fails with PoolDrained instead of dividing by zero.
+// - Otherwise: deposits at the reserve ratio and mints LP proportional to the smaller side.
+pub fn quote_add_liquidity(
+    amount_a_desired: u64,
+    amount_b_desired: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+) -> Result<AddLiquidityQuote> {
+    if lp_supply == 0 {
+        let initial = initial_liquidity(amount_a_desired, amount_b_desired)?;
+        require!(initial > MINIMUM_LIQUIDITY, ErrorCode::InsufficientInitialLiquidity);
+        return Ok(AddLiquidityQuote {
+            amount_a: amount_a_desired,
+            amount_b: amount_b_desired,
+            liquidity: initial - MINIMUM_LIQUIDITY,
+            locked_liquidity: MINIMUM_LIQUIDITY,
+        });
+    }
+
+    require!(reserve_a > 0 && reserve_b > 0, ErrorCode::PoolDrained);
+
+    // Paired amount rounded up
+    let amount_b_optimal = quote_paired_amount(amount_a_desired, reserve_a, reserve_b)?;
+    let (amount_a, amount_b) = if amount_b_optimal <= amount_b_desired {
+        (amount_a_desired, amount_b_optimal)
+    } else {
+        (quote_paired_amount(amount_b_desired, reserve_b, reserve_a)?, amount_b_desired)
+    };
+
+    let liquidity = liquidity_for_deposit(amount_a, amount_b, reserve_a, reserve_b, lp_supply)?;
+    require!(liquidity > 0, ErrorCode::ZeroLiquidityMinted);
+
+    Ok(AddLiquidityQuote { amount_a, amount_b, liquidity, locked_liquidity: 0 })
+}
+
+// Share of `reserve` owned by `lp_amount` LP tokens. Rounded down.
+pub fn share_of_reserve(lp_amount: u64, reserve: u64, lp_supply: u64) -> Result<u64> {
+    mul_div(lp_amount, reserve, lp_supply, Rounding::Down)
+}
+
+// Constant-product output for `amount_in` after the pool fee. Rounded down.
+// amount_out = amount_in_after_fee * reserve_out / (reserve_in + amount_in_after_fee)
+pub fn swap_output(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fee_basis_points: u64,
+) -> Result<u64> {
+    require!(reserve_in > 0 && reserve_out > 0, ErrorCode::PoolEmpty);
+
+    let fee_multiplier = checked_sub(FEE_DENOMINATOR, fee_basis_points)?;
+    let amount_in_after_fee = (amount_in as u128) * (fee_multiplier as u128);
+    let numerator = amount_in_after_fee
+        .checked_mul(reserve_out as u128)
+        .ok_or(error!(ErrorCode::Overflow))?;
+    let denominator = (reserve_in as u128) * (FEE_DENOMINATOR as u128) + amount_in_after_fee;
+
+    u64::try_from(numerator / denominator).map_err(|_| error!(ErrorCode::Overflow))
+}
+
+// Protocol part of the fee paid on a swap of `amount_in`. Rounded down, in favour of the LPs.
+pub fn protocol_fee(amount_in: u64, fee_basis_points: u64) -> Result<u64> {
+    let fee = mul_div(amount_in, fee_basis_points, FEE_DENOMINATOR, Rounding::Down)?;
+    Ok(fee / PROTOCOL_FEE_SHARE_DENOMINATOR)
+}
+
+// Result of quoting a swap
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_out: u64,        // Output token paid out
+    pub protocol_fee: u64,      // Part of the fee accrued to the protocol (in the input token)
+}
+
+// Quote selling `amount_in` of token A (`a_to_b`) or token B against `reserves` (A, B)
+pub fn quote_swap(amount_in: u64, a_to_b: bool, reserves: (u64, u64), fee_basis_points: u64) -> Result<SwapQuote> {
+    let (reserve_in, reserve_out) = if a_to_b { reserves } else { (reserves.1, reserves.0) };
+    Ok(SwapQuote {
+        amount_out: swap_output(amount_in, reserve_in, reserve_out, fee_basis_points)?,
+        protocol_fee: protocol_fee(amount_in, fee_basis_points)?,
+    })
+}
+
+// Part of a single-sided deposit of `amount_in` to swap into the other token so that the
+// remainder and the swap output match the post-swap reserve ratio (Uniswap v2 "zap" formula):
+// s = (sqrt((r * (D + g))^2 + 4 * g * D * r * a) - r * (D + g)) / (2 * g)
+// with r = reserve_in, a = amount_in, D = FEE_DENOMINATOR and g = D - fee. Rounded down.
+pub fn zap_swap_amount(amount_in: u64, reserve_in: u64, fee_basis_points: u64) -> Result<u64> {
+    require!(reserve_in > 0, ErrorCode::PoolEmpty);
+
+    let d = FEE_DENOMINATOR as u128;
+    let g = checked_sub(FEE_DENOMINATOR, fee_basis_points)? as u128;
+    require!(g > 0, ErrorCode::DivisionByZero);
+    let r = reserve_in as u128;
+    let a = amount_in as u128;
+
+    let b = r * (d + g);
+    let discriminant_sqrt = match b
+        .checked_mul(b)
+        .and_then(|b_squared| (4 * g * d * r).checked_mul(a).and_then(|c| b_squared.checked_add(c)))
+    {
+        Some(discriminant) => integer_sqrt(discriminant),
+        // Large reserves: sqrt(r) * sqrt(r * (D + g)^2 + 4 * g * D * a), off by at most ~1/sqrt(r)
+        None => {
+            let inner = r * (d + g) * (d + g) + 4 * g * d * a;
+            integer_sqrt(r)
+                .checked_mul(integer_sqrt(inner))
+                .ok_or(error!(ErrorCode::Overflow))?
+        }
+    };
+
+    let swap_amount = discriminant_sqrt.saturating_sub(b) / (2 * g);
+    // Never swap more than the deposit (guards the approximated branch)
+    Ok(std::cmp::min(u64::try_from(swap_amount).map_err(|_| error!(ErrorCode::Overflow))?, amount_in))
+}
+
+// Result of quoting a single-sided deposit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SingleSidedDepositQuote {
+    pub swap_amount: u64,           // Part of the input swapped into the other token
+    pub swap_out: u64,              // Output of that swap
+    pub protocol_fee: u64,          // Protocol part of the swap fee (in the deposited token)
+    pub deposit: AddLiquidityQuote, // Two-sided deposit made with the remainder and the swap output
+    pub amount_pulled: u64,         // Deposited token taken from the depositor
+    pub output_returned: u64,       // Swap output not used by the deposit (returned to the depositor)
+}
+
+// Quote a deposit of `amount_in` of a single token (A when `a_to_b`): swaps the zap amount into the
+// other token, then deposits both legs. The swap leg is priced like `swap`, on the LP reserves of the
+// local vaults (`local_reserves`); the deposit leg on the combined `reserves` after the swap, the basis
+// LP is minted on. The protocol fee of the swap leg does not join the reserves, as in `swap`.
+pub fn quote_add_liquidity_single_sided(
+    amount_in: u64,
+    a_to_b: bool,
+    reserves: (u64, u64),
+    lp_supply: u64,
+    local_reserves: (u64, u64),
+    fee_basis_points: u64,
+) -> Result<SingleSidedDepositQuote> {
+    let local_reserve_in = if a_to_b { local_reserves.0 } else { local_reserves.1 };
+    let swap_amount = zap_swap_amount(amount_in, local_reserve_in, fee_basis_points)?;
+    let swap = quote_swap(swap_amount, a_to_b, local_reserves, fee_basis_points)?;
+    let (swap_out, protocol_fee) = (swap.amount_out, swap.protocol_fee);
+    require!(swap_out > 0, ErrorCode::ZeroLiquidityMinted);
+
+    // Deposit leg, quoted against the combined reserves after the swap leg
+    let (reserve_in, reserve_out) = if a_to_b { reserves } else { (reserves.1, reserves.0) };
+    let reserve_in_after = checked_add(reserve_in, checked_sub(swap_amount, protocol_fee)?)?;
+    let reserve_out_after = checked_sub(reserve_out, swap_out)?;
+    let remainder = checked_sub(amount_in, swap_amount)?;
+    let deposit = if a_to_b {
+        quote_add_liquidity(remainder, swap_out, reserve_in_after, reserve_out_after, lp_supply)?
+    } else {
+        quote_add_liquidity(swap_out, remainder, reserve_out_after, reserve_in_after, lp_supply)?
+    };
+    require!(deposit.locked_liquidity == 0, ErrorCode::PoolEmpty); // Only reachable with a non-empty pool
+
+    let (deposit_in, deposit_out) = if a_to_b { (deposit.amount_a, deposit.amount_b) } else { (deposit.amount_b, deposit.amount_a) };
+    Ok(SingleSidedDepositQuote {
+        swap_amount,
+        swap_out,
+        protocol_fee,
+        deposit,
+        amount_pulled: checked_add(swap_amount, deposit_in)?,
+        output_returned: checked_sub(swap_out, deposit_out)?,
+    })
+}
+
+// Result of quoting a single-sided withdrawal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SingleSidedWithdrawalQuote {
+    pub kept_share: u64,        // Share of the withdrawn token
+    pub swapped_share: u64,     // Share of the other token, swapped back into the pool
+    pub swap_out: u64,          // Output of that swap
+    pub protocol_fee: u64,      // Protocol part of the swap fee (in the other token)
+    pub amount_out: u64,        // Withdrawn token paid out: kept_share + swap_out
+}
+
+// Quote burning `lp_amount` LP for a single token (A when `withdraw_a`). The LP share is taken from
+// the combined reserves; the unwanted share is swapped back against the local vaults after removal.
+pub fn quote_remove_liquidity_single_sided(
+    lp_amount: u64,
+    withdraw_a: bool,
+    reserves: (u64, u64),
+    lp_supply: u64,
+    local_reserves: (u64, u64),
+    fee_basis_points: u64,
+) -> Result<SingleSidedWithdrawalQuote> {
+    require!(lp_supply > 0, ErrorCode::PoolEmpty);
+    let share_a = share_of_reserve(lp_amount, reserves.0, lp_supply)?;
+    let share_b = share_of_reserve(lp_amount, reserves.1, lp_supply)?;
+
+    let (vault_a, vault_b) = local_reserves;
+    require!(share_a <= vault_a && share_b <= vault_b, ErrorCode::InsufficientLocalReserves);
+    let (kept_share, swapped_share, reserve_in, reserve_out) = if withdraw_a {
+        (share_a, share_b, vault_b - share_b, vault_a - share_a)
+    } else {
+        (share_b, share_a, vault_a - share_a, vault_b - share_b)
+    };
+    let (swap_out, protocol_fee) = if swapped_share > 0 {
+        let swap = quote_swap(swapped_share, true, (reserve_in, reserve_out), fee_basis_points)?;
+        (swap.amount_out, swap.protocol_fee)
+    } else {
+        (0, 0)
+    };
+
+    let amount_out = checked_add(kept_share, swap_out)?;
+    require!(amount_out > 0, ErrorCode::ZeroLiquidityBurned);
+    Ok(SingleSidedWithdrawalQuote { kept_share, swapped_share, swap_out, protocol_fee, amount_out })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_locks_minimum_liquidity() {
+        let quote = quote_add_liquidity(100_000_000, 100_000_000, 0, 0, 0).unwrap();
+        assert_eq!(quote.amount_a, 100_000_000);
+        assert_eq!(quote.amount_b, 100_000_000);
+        assert_eq!(quote.liquidity, 100_000_000 - MINIMUM_LIQUIDITY);
+        assert_eq!(quote.locked_liquidity, MINIMUM_LIQUIDITY);
+    }
+
+    #[test]
+    fn first_deposit_below_minimum_fails() {
+        let err = quote_add_liquidity(1_000, 1_000, 0, 0, 0).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::InsufficientInitialLiquidity));
+    }
+
+    #[test]
+    fn drained_reserve_a_fails_with_pool_drained() {
+        let err = quote_add_liquidity(1_000, 1_000, 0, 5_000, 10_000).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::PoolDrained));
+    }
+
+    #[test]
+    fn drained_reserve_b_fails_with_pool_drained() {
+        let err = quote_add_liquidity(1_000, 1_000, 5_000, 0, 10_000).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::PoolDrained));
+    }
+
+    #[test]
+    fn fully_drained_pool_fails_with_pool_drained() {
+        let err = quote_add_liquidity(1_000, 1_000, 0, 0, 10_000).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::PoolDrained));
+    }
+
+    #[test]
+    fn deposit_follows_reserve_ratio() {
+        // 1:2 pool, the desired B amount is the limiting side
+        let quote = quote_add_liquidity(1_000, 1_000, 10_000, 20_000, 10_000).unwrap();
+        assert_eq!(quote.amount_a, 500);
+        assert_eq!(quote.amount_b, 1_000);
+        assert_eq!(quote.liquidity, 500);
+        assert_eq!(quote.locked_liquidity, 0);
+    }
+
+    #[test]
+    fn paired_amount_rounds_up() {
+        // 10 * 3 / 7 = 4.28.. -> 5
+        assert_eq!(quote_paired_amount(10, 7, 3).unwrap(), 5);
+        assert_eq!(mul_div(10, 3, 7, Rounding::Down).unwrap(), 4);
+    }
+
+    #[test]
+    fn mul_div_by_zero_fails() {
+        let err = mul_div(1, 1, 0, Rounding::Down).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::DivisionByZero));
+    }
+
+    #[test]
+    fn swap_on_empty_pool_fails() {
+        let err = swap_output(1_000, 0, 1_000, 30).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::PoolEmpty));
+    }
+
+    #[test]
+    fn zap_swaps_about_half_of_a_small_deposit() {
+        // Without fees and a deposit tiny relative to the reserve, the optimal swap is ~50%
+        let swap_amount = zap_swap_amount(1_000, 1_000_000_000, 0).unwrap();
+        assert!((499..=500).contains(&swap_amount), "swap_amount = {}", swap_amount);
+    }
+
+    #[test]
+    fn zap_leaves_balanced_deposit() {
+        let (reserve_in, reserve_out, fee, amount_in) = (1_000_000u64, 4_000_000u64, 30u64, 100_000u64);
+        let swap_amount = zap_swap_amount(amount_in, reserve_in, fee).unwrap();
+        let swap_out = swap_output(swap_amount, reserve_in, reserve_out, fee).unwrap();
+
+        // Remainder / swap output matches the post-swap reserve ratio to within rounding
+        let remainder = (amount_in - swap_amount) as u128;
+        let lhs = remainder * (reserve_out - swap_out) as u128;
+        let rhs = swap_out as u128 * (reserve_in + swap_amount) as u128;
+        assert!(lhs.abs_diff(rhs) * 1_000 <= rhs, "lhs = {}, rhs = {}", lhs, rhs);
+    }
+
+    #[test]
+    fn single_sided_deposit_pulls_at_most_the_input() {
+        let reserves = (1_000_000, 4_000_000);
+        let quote = quote_add_liquidity_single_sided(100_000, true, reserves, 2_000_000, reserves, 30).unwrap();
+        assert!(quote.amount_pulled <= 100_000);
+        assert_eq!(quote.deposit.amount_b + quote.output_returned, quote.swap_out);
+        assert_eq!(quote.protocol_fee, protocol_fee(quote.swap_amount, 30).unwrap());
+        assert!(quote.deposit.liquidity > 0);
+    }
+
+    #[test]
+    fn single_sided_deposit_prices_the_swap_on_local_reserves() {
+        // Half of the reserves are on Sui: the swap leg only sees the local vaults
+        let (reserves, local_reserves) = ((2_000_000, 8_000_000), (1_000_000, 4_000_000));
+        let quote = quote_add_liquidity_single_sided(100_000, true, reserves, 4_000_000, local_reserves, 30).unwrap();
+        assert_eq!(quote.swap_amount, zap_swap_amount(100_000, 1_000_000, 30).unwrap());
+        assert_eq!(quote.swap_out, swap_output(quote.swap_amount, 1_000_000, 4_000_000, 30).unwrap());
+        assert!(quote.deposit.liquidity > 0);
+    }
+
+    #[test]
+    fn single_sided_withdrawal_swaps_the_other_share() {
+        let reserves = (1_000_000, 4_000_000);
+        let quote = quote_remove_liquidity_single_sided(200_000, true, reserves, 2_000_000, reserves, 30).unwrap();
+        assert_eq!(quote.kept_share, 100_000);
+        assert_eq!(quote.swapped_share, 400_000);
+        assert_eq!(quote.swap_out, swap_output(400_000, 3_600_000, 900_000, 30).unwrap());
+        assert_eq!(quote.amount_out, quote.kept_share + quote.swap_out);
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use wormhole_anchor_sdk::wormhole;
+use crate::state::{Config, OutboundOperation};
+
+// Seed of the program's Wormhole emitter PDA
+pub const EMITTER_SEED: &[u8] = b"emitter";
+
+// Accounts required to post a message through the Wormhole core bridge.
+// Instructions that send messages collect these from their own Accounts struct.
+pub struct OutboundMessageAccounts<'info> {
+    pub wormhole_program: AccountInfo<'info>,
+    pub wormhole_bridge: AccountInfo<'info>,        // Core bridge config (holds the message fee)
+    pub wormhole_message: AccountInfo<'info>,       // Message account created by the core bridge (PDA of this program)
+    pub wormhole_emitter: AccountInfo<'info>,       // This program's emitter PDA
+    pub wormhole_sequence: AccountInfo<'info>,      // Emitter sequence tracker owned by the core bridge
+    pub wormhole_fee_collector: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+// How the message account signs the `post_message` CPI
+pub enum MessageSigner<'a> {
+    Keypair,              // New keypair signing the transaction itself
+    Pda(&'a [&'a [u8]]),  // PDA of this program: its signer seeds (including bump)
+}
+
+// Post a payload to Wormhole using the batch nonce and the consistency level configured for `operation`
+pub fn post_outbound_message<'info>(
+    accounts: OutboundMessageAccounts<'info>,
+    config: &Config,
+    operation: OutboundOperation,
+    payload: Vec<u8>,
+    emitter_bump: u8,
+    message_signer: MessageSigner,
+) -> Result<()> {
+    // Pay the Wormhole message fee, if any
+    let fee = {
+        let bridge_data = accounts.wormhole_bridge.try_borrow_data()?;
+        wormhole::BridgeData::try_deserialize(&mut &bridge_data[..])?.fee()
+    };
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                system_program::Transfer {
+                    from: accounts.payer.clone(),
+                    to: accounts.wormhole_fee_collector.clone(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let consistency = config.consistency_for(operation);
+    msg!("Posting {:?} message: nonce={}, consistency={:?}", operation, config.batch_nonce, consistency);
+
+    let emitter_seeds: &[&[u8]] = &[EMITTER_SEED, &[emitter_bump]];
+    let signer_seeds: Vec<&[&[u8]]> = match message_signer {
+        // The keypair's signature is forwarded as is: only the emitter is signed for by the program
+        MessageSigner::Keypair => {
+            require!(accounts.wormhole_message.is_signer, anchor_lang::error::ErrorCode::AccountNotSigner);
+            vec![emitter_seeds]
+        }
+        MessageSigner::Pda(message_seeds) => vec![emitter_seeds, message_seeds],
+    };
+
+    wormhole::post_message(
+        CpiContext::new_with_signer(
+            accounts.wormhole_program,
+            wormhole::PostMessage {
+                config: accounts.wormhole_bridge,
+                message: accounts.wormhole_message,
+                emitter: accounts.wormhole_emitter,
+                sequence: accounts.wormhole_sequence,
+                payer: accounts.payer,
+                fee_collector: accounts.wormhole_fee_collector,
+                clock: accounts.clock,
+                rent: accounts.rent,
+                system_program: accounts.system_program,
+            },
+            &signer_seeds,
+        ),
+        config.batch_nonce,
+        payload,
+        consistency.into(),
+    )?;
+
+    Ok(())
+}
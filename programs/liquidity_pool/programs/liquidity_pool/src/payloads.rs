@@ -7,6 +7,9 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub enum BridgeOperationCode {
     AddLiquidityCompletion = 0, // Example: Corresponds to completing AddLiquidity on Solana
     RemoveLiquidityCompletion = 1, // Example: Corresponds to completing RemoveLiquidity on Solana
+    SwapIntent = 2,                // Swap sold on Sui, output delivered from the Solana pool
+    ReserveSnapshot = 3,           // Periodic state of the mirrored Sui pool
+    LpMigration = 4,               // LP tokens burned on Sui, to be minted on Solana
     // Add other operation codes defined in your cross-chain protocol
 }
 
@@ -16,6 +19,11 @@ pub struct AddLiquidityCompletionPayload {
     pub recipient_address: [u8; 32], // Solana address (as bytes) to receive LP tokens
     pub lp_amount_to_mint: u64,      // Amount of LP tokens calculated on Sui side
     pub original_pool_id: [u8; 32],  // Pool ID from Sui to verify against Solana pool
+    pub relayer_fee: u64,            // LP tokens (taken from lp_amount_to_mint) paid to the VAA submitter, 0 for none
+    pub target_token_account: Option<[u8; 32]>, // LP token account owned by the recipient to deliver to (defaults to the recipient's ATA)
+    pub receiver_program: Option<[u8; 32]>,     // Program CPI'd with the delivered amounts (see receiver.rs)
+    pub amount_a_deposited: u64,     // Token A deposited into the Sui pool for this LP (global ledger)
+    pub amount_b_deposited: u64,     // Token B deposited into the Sui pool for this LP (global ledger)
 }
 
 // Payload structure for completing a RemoveLiquidity operation initiated on Sui
@@ -25,6 +33,59 @@ pub struct RemoveLiquidityCompletionPayload {
     pub amount_a_to_transfer: u64,   // Amount of token A calculated on Sui side
     pub amount_b_to_transfer: u64,   // Amount of token B calculated on Sui side
     pub original_pool_id: [u8; 32],  // Pool ID from Sui to verify against Solana pool
+    pub relayer_fee_a: u64,          // Token A (taken from amount_a_to_transfer) paid to the VAA submitter, 0 for none
+    pub relayer_fee_b: u64,          // Token B (taken from amount_b_to_transfer) paid to the VAA submitter, 0 for none
+    pub target_token_a_account: Option<[u8; 32]>, // Token A account owned by the recipient to deliver to (defaults to the recipient's ATA)
+    pub target_token_b_account: Option<[u8; 32]>, // Token B account owned by the recipient to deliver to (defaults to the recipient's ATA)
+    pub receiver_program: Option<[u8; 32]>,       // Program CPI'd with the delivered amounts (see receiver.rs)
+    pub lp_amount_burned: u64,       // Sui LP tokens burned for this withdrawal (global ledger)
+}
+
+// Payload structure for a swap intent initiated on Sui (input tokens are held by the Sui pool)
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct SwapIntentPayload {
+    pub recipient_address: [u8; 32], // Solana address (as bytes) to receive the output tokens
+    pub refund_address: [u8; 32],    // Sui address refunded if the swap can't be executed
+    pub original_pool_id: [u8; 32],  // Pool ID from Sui to verify against Solana pool
+    pub amount_in: u64,              // Amount of the input token sold on Sui
+    pub min_amount_out: u64,         // Minimum output the user accepts (before the relayer fee)
+    pub deadline: i64,               // Unix timestamp after which the intent is refunded instead of executed
+    pub a_to_b: bool,                // true: token A in, token B out; false: token B in, token A out
+    pub relayer_fee: u64,            // Output tokens (taken from the swap output) paid to the VAA submitter, 0 for none
+    pub target_token_account: Option<[u8; 32]>, // Output token account owned by the recipient (defaults to the recipient's ATA)
+}
+
+// Payload posted back to Sui when a swap intent can't be executed (min output not met, pool paused, deadline passed)
+// Sent with OutboundOperation::SwapRefund as its first byte.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct SwapRefundPayload {
+    pub refund_address: [u8; 32],    // Sui address to refund
+    pub original_pool_id: [u8; 32],  // Pool ID of the swap intent
+    pub amount_in: u64,              // Input amount to refund
+    pub a_to_b: bool,                // Direction of the original intent (identifies the input token)
+    pub intent_sequence: u64,        // Wormhole sequence of the swap intent VAA
+}
+
+// Payload structure for a periodic reserve snapshot of the mirrored Sui pool
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct ReserveSnapshotPayload {
+    pub original_pool_id: [u8; 32],  // Pool ID from Sui to verify against Solana pool
+    pub reserve_a: u64,              // Token A reserve of the Sui pool
+    pub reserve_b: u64,              // Token B reserve of the Sui pool
+    pub lp_supply: u64,              // LP supply of the Sui pool
+    pub timestamp: i64,              // Time the snapshot was taken on Sui (Unix timestamp)
+}
+
+// Payload structure for moving an LP position between chains (burned on the source, minted on the destination).
+// Used inbound (BridgeOperationCode::LpMigration) and outbound (OutboundOperation::LpMigration).
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct LpMigrationPayload {
+    pub sender_address: [u8; 32],    // Owner of the burned LP tokens on the source chain
+    pub recipient_address: [u8; 32], // Address receiving the LP tokens on the destination chain
+    pub original_pool_id: [u8; 32],  // Cross-chain pool ID
+    pub lp_amount: u64,              // LP tokens burned on the source chain
+    pub reserve_a_share: u64,        // Token A share of the global reserves represented by lp_amount
+    pub reserve_b_share: u64,        // Token B share of the global reserves represented by lp_amount
 }
 
 // Generic payload wrapper (optional, but can be useful)
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed};
+
+// Callback interface for receiver programs of bridge completions.
+// A receiver program (e.g. a vault) implements an Anchor instruction named `on_omnisphere_delivery`
+// taking a `DeliveryCallback` argument. It is invoked with these accounts, in order:
+//   0. delivery_authority (signer) - PDA [b"delivery", pool] of this program, proves the call comes
+//                                    from OmniSphere. It owns nothing, unlike the pool authority.
+//   1. recipient                - Recipient address from the VAA payload
+//   2..n. delivered token accounts (the accounts that just received tokens)
+//   followed by every account passed in `remaining_accounts` of the process instruction.
+
+// Seed of the per-pool delivery authority PDA. The receiver program is chosen by the user, so the
+// callback must never carry the signature of the pool authority (vaults, LP mint).
+pub const DELIVERY_AUTHORITY_SEED: &[u8] = b"delivery";
+
+// Anchor discriminator of `on_omnisphere_delivery`: sha256("global:on_omnisphere_delivery")[..8]
+pub const DELIVERY_CALLBACK_DISCRIMINATOR: [u8; 8] = [0xbf, 0x2d, 0x1e, 0x51, 0x91, 0x52, 0x7c, 0x18];
+
+// Data passed to the receiver program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DeliveryCallback {
+    pub pool_id: [u8; 32],       // Cross-chain pool ID
+    pub operation_code: u8,      // BridgeOperationCode of the completed operation
+    pub wormhole_sequence: u64,  // Sequence of the VAA that triggered the delivery
+    pub lp_amount: u64,          // LP tokens delivered (0 if none)
+    pub amount_a: u64,           // Token A delivered (0 if none)
+    pub amount_b: u64,           // Token B delivered (0 if none)
+}
+
+// Helper function CPI-ing into the receiver program, signed by the delivery authority PDA
+pub fn invoke_receiver_program<'info>(
+    receiver_program: &AccountInfo<'info>,
+    delivery_authority: &AccountInfo<'info>,
+    pool_key: Pubkey,
+    delivery_authority_bump: u8,
+    recipient: &AccountInfo<'info>,
+    delivered_token_accounts: &[AccountInfo<'info>],
+    remaining_accounts: &[AccountInfo<'info>],
+    callback: &DeliveryCallback,
+) -> Result<()> {
+    let mut data = DELIVERY_CALLBACK_DISCRIMINATOR.to_vec();
+    callback.serialize(&mut data)?;
+
+    let mut account_metas = vec![
+        AccountMeta::new_readonly(delivery_authority.key(), true),
+        AccountMeta::new_readonly(recipient.key(), false),
+    ];
+    let mut account_infos = vec![delivery_authority.clone(), recipient.clone()];
+    for account in delivered_token_accounts.iter().chain(remaining_accounts.iter()) {
+        account_metas.push(if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        });
+        account_infos.push(account.clone());
+    }
+    account_infos.push(receiver_program.clone());
+
+    let instruction = Instruction {
+        program_id: receiver_program.key(),
+        accounts: account_metas,
+        data,
+    };
+
+    let seeds = &[DELIVERY_AUTHORITY_SEED, pool_key.as_ref(), &[delivery_authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+    msg!("Invoked receiver program {}", receiver_program.key());
+    Ok(())
+}
@@ -1,16 +1,26 @@
 use anchor_lang::prelude::*;
 
+// Maximum stored bridge payload (operation code + operation payload)
+pub const MAX_BRIDGE_PAYLOAD_SIZE: usize = 256;
+
+// One account per processed VAA: PDA seeded with [b"bridge_request", vaa_hash].
+// Its creation fails if the VAA was already processed.
 #[account]
 #[derive(Default)]
 pub struct BridgeRequest {
     pub wormhole_sequence: u64,     // Wormhole message sequence number
     pub emitter_chain: u16,         // Source chain ID (Wormhole Chain ID)
     pub emitter_address: [u8; 32],  // Source emitter address (Wormhole format)
+    pub guardian_set_index: u32,    // Guardian set that signed the VAA
     pub status: BridgeStatus,       // Bridge request status
     pub payload: Vec<u8>,           // Bridge operation payload (variable size)
     pub created_at: i64,            // Creation timestamp
-    // Add bump seed if this account is a PDA
-    // pub bump: u8,
+    pub bump: u8,                   // PDA bump seed
+}
+
+impl BridgeRequest {
+    // Discriminator (8) + u64 (1) + u16 (1) + [u8; 32] (1) + u32 (1) + Enum (1) + Vec (4 + MAX_BRIDGE_PAYLOAD_SIZE) + i64 (1) + u8 (1)
+    pub const SIZE: usize = 8 + 8 + 2 + 32 + 4 + 1 + (4 + MAX_BRIDGE_PAYLOAD_SIZE) + 8 + 1;
 }
 
 // Define BridgeStatus enum
@@ -26,11 +36,3 @@ impl Default for BridgeStatus {
         BridgeStatus::Pending
     }
 }
-
-// Note: Size calculation for accounts with Vec<u8> is tricky.
-// Anchor usually handles this, but for manual calculation:
-// Discriminator (8) + u64 (1) + u16 (1) + [u8; 32] (1) + Enum (1 byte for status) + Vec (4 bytes for length) + i64 (1) + Payload size
-// The maximum size depends on the maximum payload length allowed.
-// Let's assume a max payload of 1024 bytes for estimation as per README.
-// SIZE ~ 8 + 8 + 2 + 32 + 1 + 4 + 8 + 1024 = 1087 bytes (approx max)
-// It's often better to let Anchor manage size or use fixed-size arrays if possible.
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::wormhole;
+
+// Number of outbound operation slots reserved in the config.
+// Kept larger than the current number of operations so new ones can be added without resizing.
+pub const MAX_OUTBOUND_OPERATIONS: usize = 8;
+
+// Program-wide configuration (singleton PDA seeded with b"config")
+#[account]
+#[derive(Default)]
+pub struct Config {
+    pub admin: Pubkey,              // Account allowed to change the configuration
+    pub batch_nonce: u32,           // Nonce (batch ID) attached to every outbound Wormhole message
+    pub consistency_levels: [ConsistencyLevel; MAX_OUTBOUND_OPERATIONS], // Indexed by OutboundOperation
+    pub rent_reimbursement_lamports: u64, // Max lamports of rent reimbursed to the VAA submitter per processed VAA (0 disables)
+    pub max_price_drift_bps: u64,   // Price divergence from the Sui pool that raises a drift alarm (basis points)
+    pub bump: u8,                   // PDA bump seed for the config account
+}
+
+impl Config {
+    // Discriminator (8) + Pubkey (1) + u32 (1) + Enum (1 byte * MAX_OUTBOUND_OPERATIONS) + u64 (2) + u8 (1)
+    pub const SIZE: usize = 8 + 32 + 4 + MAX_OUTBOUND_OPERATIONS + (8 * 2) + 1;
+
+    // Consistency level configured for a given outbound operation
+    pub fn consistency_for(&self, operation: OutboundOperation) -> ConsistencyLevel {
+        self.consistency_levels[operation as usize]
+    }
+}
+
+// Default drift alarm threshold: 1% price divergence between the mirrored pools
+pub const DEFAULT_MAX_PRICE_DRIFT_BPS: u64 = 100;
+
+// Wormhole consistency level requested when posting a message.
+// Mirrors wormhole::Finality so it can be stored and exposed in the IDL.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConsistencyLevel {
+    Confirmed,
+    Finalized,
+}
+
+impl Default for ConsistencyLevel {
+    fn default() -> Self {
+        ConsistencyLevel::Finalized // Safest choice until ops decides otherwise
+    }
+}
+
+impl From<ConsistencyLevel> for wormhole::Finality {
+    fn from(level: ConsistencyLevel) -> Self {
+        match level {
+            ConsistencyLevel::Confirmed => wormhole::Finality::Confirmed,
+            ConsistencyLevel::Finalized => wormhole::Finality::Finalized,
+        }
+    }
+}
+
+// Types of messages this program posts to Wormhole.
+// The discriminant is the index into Config::consistency_levels, so never reorder existing variants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutboundOperation {
+    AddLiquidity = 0,    // Liquidity added on Solana, mirrored to Sui
+    RemoveLiquidity = 1, // Liquidity removed on Solana, mirrored to Sui
+    SwapRefund = 2,      // Cross-chain swap intent refunded on Sui
+    LpMigration = 3,     // LP tokens burned on Solana, to be minted on Sui
+}
+
+// Consistency level override for a single outbound operation (used by update_wormhole_settings)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OperationConsistency {
+    pub operation: OutboundOperation,
+    pub level: ConsistencyLevel,
+}
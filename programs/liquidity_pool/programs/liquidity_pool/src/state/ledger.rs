@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Global LP accounting for a cross-chain pool: PDA seeded with [b"ledger", pool_id].
+// "Local" is the Solana pool, "remote" the mirrored Sui pool. Liquidity quotes use the totals
+// so one LP token represents the same share of the combined reserves on both chains.
+#[account]
+#[derive(Default)]
+pub struct PoolLedger {
+    pub pool_id: [u8; 32],          // Cross-chain pool ID (same as Pool::pool_id)
+    pub pool: Pubkey,               // Solana pool account
+    pub local_lp_supply: u64,       // LP tokens minted on Solana
+    pub remote_lp_supply: u64,      // LP tokens minted on Sui
+    pub local_reserve_a: u64,       // Token A held by the Solana pool
+    pub local_reserve_b: u64,       // Token B held by the Solana pool
+    pub remote_reserve_a: u64,      // Token A held by the Sui pool
+    pub remote_reserve_b: u64,      // Token B held by the Sui pool
+    pub last_updated_at: i64,       // Last update timestamp (Unix timestamp)
+    pub bump: u8,                   // PDA bump seed
+}
+
+impl PoolLedger {
+    // Discriminator (8) + [u8; 32] (1) + Pubkey (1) + u64 (6) + i64 (1) + u8 (1)
+    pub const SIZE: usize = 8 + 32 + 32 + (8 * 6) + 8 + 1;
+
+    pub fn total_lp_supply(&self) -> Result<u64> {
+        self.local_lp_supply.checked_add(self.remote_lp_supply).ok_or(error!(ErrorCode::Overflow))
+    }
+
+    pub fn total_reserve_a(&self) -> Result<u64> {
+        self.local_reserve_a.checked_add(self.remote_reserve_a).ok_or(error!(ErrorCode::Overflow))
+    }
+
+    pub fn total_reserve_b(&self) -> Result<u64> {
+        self.local_reserve_b.checked_add(self.remote_reserve_b).ok_or(error!(ErrorCode::Overflow))
+    }
+
+    // Tokens/LP added on the Solana side
+    pub fn add_local(&mut self, amount_a: u64, amount_b: u64, lp_amount: u64) -> Result<()> {
+        self.local_reserve_a = self.local_reserve_a.checked_add(amount_a).ok_or(error!(ErrorCode::Overflow))?;
+        self.local_reserve_b = self.local_reserve_b.checked_add(amount_b).ok_or(error!(ErrorCode::Overflow))?;
+        self.local_lp_supply = self.local_lp_supply.checked_add(lp_amount).ok_or(error!(ErrorCode::Overflow))?;
+        self.touch()
+    }
+
+    // Tokens/LP removed on the Solana side
+    pub fn sub_local(&mut self, amount_a: u64, amount_b: u64, lp_amount: u64) -> Result<()> {
+        self.local_reserve_a = self.local_reserve_a.checked_sub(amount_a).ok_or(error!(ErrorCode::Underflow))?;
+        self.local_reserve_b = self.local_reserve_b.checked_sub(amount_b).ok_or(error!(ErrorCode::Underflow))?;
+        self.local_lp_supply = self.local_lp_supply.checked_sub(lp_amount).ok_or(error!(ErrorCode::Underflow))?;
+        self.touch()
+    }
+
+    // Tokens/LP added on the Sui side (reported by a bridged operation)
+    pub fn add_remote(&mut self, amount_a: u64, amount_b: u64, lp_amount: u64) -> Result<()> {
+        self.remote_reserve_a = self.remote_reserve_a.checked_add(amount_a).ok_or(error!(ErrorCode::Overflow))?;
+        self.remote_reserve_b = self.remote_reserve_b.checked_add(amount_b).ok_or(error!(ErrorCode::Overflow))?;
+        self.remote_lp_supply = self.remote_lp_supply.checked_add(lp_amount).ok_or(error!(ErrorCode::Overflow))?;
+        self.touch()
+    }
+
+    // Tokens/LP removed on the Sui side (reported by a bridged operation)
+    pub fn sub_remote(&mut self, amount_a: u64, amount_b: u64, lp_amount: u64) -> Result<()> {
+        self.remote_reserve_a = self.remote_reserve_a.checked_sub(amount_a).ok_or(error!(ErrorCode::Underflow))?;
+        self.remote_reserve_b = self.remote_reserve_b.checked_sub(amount_b).ok_or(error!(ErrorCode::Underflow))?;
+        self.remote_lp_supply = self.remote_lp_supply.checked_sub(lp_amount).ok_or(error!(ErrorCode::Underflow))?;
+        self.touch()
+    }
+
+    fn touch(&mut self) -> Result<()> {
+        self.last_updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
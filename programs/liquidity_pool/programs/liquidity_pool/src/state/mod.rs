@@ -1,7 +1,15 @@
 pub mod pool;
 pub mod position;
 pub mod bridge_request;
+pub mod config;
+pub mod sequence_tracker;
+pub mod ledger;
+pub mod remote_pool_state;
 
 pub use pool::*;
 pub use position::*;
 pub use bridge_request::*;
+pub use config::*;
+pub use sequence_tracker::*;
+pub use ledger::*;
+pub use remote_pool_state::*;
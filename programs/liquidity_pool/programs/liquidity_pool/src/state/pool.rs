@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
 
 #[account]
 #[derive(Default)]
@@ -26,6 +27,21 @@ impl Pool {
     // Calculate size based on fields
     // Discriminator (8) + Pubkey (32 * 6) + u64 (5) + [u8; 32] (1) + u8 (1) + i64 (1) + u8 (4)
     pub const SIZE: usize = 8 + (32 * 6) + (8 * 5) + 32 + 1 + 8 + 4;
+
+    // Vault balances minus the accrued protocol fees, i.e. the reserves owned by LPs
+    pub fn lp_reserves(&self, vault_a: u64, vault_b: u64) -> (u64, u64) {
+        (vault_a.saturating_sub(self.protocol_fee_a), vault_b.saturating_sub(self.protocol_fee_b))
+    }
+
+    // Record the protocol part of a swap fee paid in token A (`a_side`) or token B
+    pub fn accrue_protocol_fee(&mut self, a_side: bool, amount: u64) -> Result<()> {
+        if a_side {
+            self.protocol_fee_a = self.protocol_fee_a.checked_add(amount).ok_or(error!(ErrorCode::Overflow))?;
+        } else {
+            self.protocol_fee_b = self.protocol_fee_b.checked_add(amount).ok_or(error!(ErrorCode::Overflow))?;
+        }
+        Ok(())
+    }
 }
 
 // Enum for Pool Status (optional but good practice)
@@ -42,5 +58,12 @@ impl Default for PoolStatus {
     }
 }
 
+// Token of a pool selected by single-sided instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenSide {
+    A,
+    B,
+}
+
 // Implement conversion from u8 if needed, or use the enum directly in the Pool struct
 // For simplicity, the Pool struct uses u8 for status directly as shown in the README example.
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// Last reserve snapshot received from the mirrored Sui pool: PDA seeded with [b"remote_pool", pool_id].
+// Gives cross-chain pricing inputs and raises a drift alarm when the two pools' prices diverge.
+#[account]
+#[derive(Default)]
+pub struct RemotePoolState {
+    pub pool_id: [u8; 32],          // Cross-chain pool ID
+    pub reserve_a: u64,             // Token A reserve of the Sui pool
+    pub reserve_b: u64,             // Token B reserve of the Sui pool
+    pub lp_supply: u64,             // LP supply of the Sui pool
+    pub snapshot_timestamp: i64,    // Time the snapshot was taken on Sui (Unix timestamp)
+    pub received_at: i64,           // Time the snapshot was processed on Solana
+    pub wormhole_sequence: u64,     // Sequence of the snapshot VAA
+    pub price_drift_bps: u64,       // Price divergence between the pools at reception (basis points)
+    pub drift_alarm: bool,          // Set when price_drift_bps exceeds Config::max_price_drift_bps
+    pub bump: u8,                   // PDA bump seed
+}
+
+impl RemotePoolState {
+    // Discriminator (8) + [u8; 32] (1) + u64 (3) + i64 (2) + u64 (2) + bool (1) + u8 (1)
+    pub const SIZE: usize = 8 + 32 + (8 * 3) + (8 * 2) + (8 * 2) + 1 + 1;
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+// Registration of a foreign emitter and the next Wormhole sequence expected from it.
+// PDA seeded with [b"sequence_tracker", emitter_chain (LE bytes), emitter_address].
+// VAAs from emitters without a tracker are rejected. When `ordered` is set, VAAs from this
+// emitter must be processed strictly in sequence order; otherwise in any order.
+#[account]
+#[derive(Default)]
+pub struct SequenceTracker {
+    pub emitter_chain: u16,             // Source chain ID (Wormhole Chain ID)
+    pub emitter_address: [u8; 32],      // Source emitter address (Wormhole format)
+    pub ordered: bool,                  // Enforce in-order processing for this emitter
+    pub next_expected_sequence: u64,    // Sequence the next processed VAA must carry (when ordered)
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl SequenceTracker {
+    // Discriminator (8) + u16 (1) + [u8; 32] (1) + bool (1) + u64 (1) + u8 (1)
+    pub const SIZE: usize = 8 + 2 + 32 + 1 + 8 + 1;
+}
@@ -0,0 +1,233 @@
+use anchor_lang::error;
+use liquidity_pool::errors::ErrorCode;
+use liquidity_pool::math::{self, Rounding, FEE_DENOMINATOR, MINIMUM_LIQUIDITY};
+use liquidity_pool::state::Pool;
+use proptest::prelude::*;
+
+// Invariants of the pool math, checked on the functions the instruction handlers call.
+// Each test replays the handler's sequence of calls (reserves, fee accrual, LP supply) on
+// random pool states and asserts the result never favours the user over the pool.
+
+// Bounds keeping every intermediate result within u64 (deposits mint at most 2^60 LP)
+const MAX_RESERVE: u64 = 1 << 40;
+const MAX_AMOUNT: u64 = 1 << 30;
+
+fn k(reserve_a: u64, reserve_b: u64) -> u128 {
+    reserve_a as u128 * reserve_b as u128
+}
+
+fn fee() -> impl Strategy<Value = u64> {
+    0..FEE_DENOMINATOR
+}
+
+// Swap as executed by the swap handler: priced on the LP reserves, the protocol fee stays in the
+// vault but is accrued outside the LP reserves. Returns the output amount.
+fn execute_swap(pool: &mut Pool, vault_a: &mut u64, vault_b: &mut u64, amount_in: u64, a_to_b: bool) -> u64 {
+    let (reserve_a, reserve_b) = pool.lp_reserves(*vault_a, *vault_b);
+    let (reserve_in, reserve_out) = if a_to_b { (reserve_a, reserve_b) } else { (reserve_b, reserve_a) };
+    let amount_out = math::swap_output(amount_in, reserve_in, reserve_out, pool.fee_percentage).unwrap();
+    let protocol_fee = math::protocol_fee(amount_in, pool.fee_percentage).unwrap();
+    pool.accrue_protocol_fee(a_to_b, protocol_fee).unwrap();
+
+    let (vault_in, vault_out) = if a_to_b { (vault_a, vault_b) } else { (vault_b, vault_a) };
+    *vault_in += amount_in;
+    *vault_out -= amount_out;
+    amount_out
+}
+
+proptest! {
+    // Swaps
+
+    #[test]
+    fn swap_never_decreases_k(
+        reserve_a in 1..=MAX_RESERVE,
+        reserve_b in 1..=MAX_RESERVE,
+        amount_in in 1..=MAX_AMOUNT,
+        fee_percentage in fee(),
+        a_to_b: bool,
+    ) {
+        let mut pool = Pool { fee_percentage, ..Default::default() };
+        let (mut vault_a, mut vault_b) = (reserve_a, reserve_b);
+        execute_swap(&mut pool, &mut vault_a, &mut vault_b, amount_in, a_to_b);
+
+        let (after_a, after_b) = pool.lp_reserves(vault_a, vault_b);
+        prop_assert!(k(after_a, after_b) >= k(reserve_a, reserve_b));
+    }
+
+    #[test]
+    fn fee_accrual_keeps_k_growing_over_many_swaps(
+        reserve_a in 1_000..=MAX_RESERVE,
+        reserve_b in 1_000..=MAX_RESERVE,
+        fee_percentage in fee(),
+        swaps in prop::collection::vec((1..=MAX_AMOUNT, any::<bool>()), 1..20),
+    ) {
+        let mut pool = Pool { fee_percentage, ..Default::default() };
+        let (mut vault_a, mut vault_b) = (reserve_a, reserve_b);
+        let mut fees_charged = (0u64, 0u64);
+
+        for (amount_in, a_to_b) in swaps {
+            let k_before = {
+                let (lp_a, lp_b) = pool.lp_reserves(vault_a, vault_b);
+                k(lp_a, lp_b)
+            };
+            execute_swap(&mut pool, &mut vault_a, &mut vault_b, amount_in, a_to_b);
+
+            let (lp_a, lp_b) = pool.lp_reserves(vault_a, vault_b);
+            prop_assert!(k(lp_a, lp_b) >= k_before);
+            // The vaults always hold the LP reserves plus the accrued protocol fees
+            prop_assert_eq!(lp_a + pool.protocol_fee_a, vault_a);
+            prop_assert_eq!(lp_b + pool.protocol_fee_b, vault_b);
+
+            let fee_charged = math::mul_div(amount_in, fee_percentage, FEE_DENOMINATOR, Rounding::Down).unwrap();
+            if a_to_b { fees_charged.0 += fee_charged } else { fees_charged.1 += fee_charged }
+        }
+
+        // The protocol never accrues more than the fees the traders paid
+        prop_assert!(pool.protocol_fee_a <= fees_charged.0);
+        prop_assert!(pool.protocol_fee_b <= fees_charged.1);
+    }
+
+    #[test]
+    fn swap_output_rounds_down(
+        reserve_in in 1..=MAX_RESERVE,
+        reserve_out in 1..=MAX_RESERVE,
+        amount_in in 0..=MAX_AMOUNT,
+        fee_percentage in fee(),
+    ) {
+        let amount_out = math::swap_output(amount_in, reserve_in, reserve_out, fee_percentage).unwrap();
+
+        // amount_out <= amount_in_after_fee * reserve_out / (reserve_in + amount_in_after_fee), exactly
+        let after_fee = amount_in as u128 * (FEE_DENOMINATOR - fee_percentage) as u128;
+        let denominator = reserve_in as u128 * FEE_DENOMINATOR as u128 + after_fee;
+        prop_assert!(amount_out as u128 * denominator <= after_fee * reserve_out as u128);
+        prop_assert!(amount_out < reserve_out);
+    }
+
+    #[test]
+    fn swap_round_trip_never_profits(
+        reserve_a in 1..=MAX_RESERVE,
+        reserve_b in 1..=MAX_RESERVE,
+        amount_in in 1..=MAX_AMOUNT,
+        fee_percentage in fee(),
+    ) {
+        let mut pool = Pool { fee_percentage, ..Default::default() };
+        let (mut vault_a, mut vault_b) = (reserve_a, reserve_b);
+
+        let amount_b = execute_swap(&mut pool, &mut vault_a, &mut vault_b, amount_in, true);
+        let amount_a = execute_swap(&mut pool, &mut vault_a, &mut vault_b, amount_b, false);
+        prop_assert!(amount_a <= amount_in);
+    }
+
+    // Liquidity
+
+    #[test]
+    fn redemption_never_exceeds_proportional_share(
+        reserve in 0..=MAX_RESERVE,
+        lp_supply in 1..=MAX_RESERVE,
+        lp_fraction in 0.0..=1.0f64,
+    ) {
+        let lp_amount = (lp_supply as f64 * lp_fraction) as u64;
+        let share = math::share_of_reserve(lp_amount, reserve, lp_supply).unwrap();
+
+        // share * supply <= lp * reserve, and rounding costs the user less than one unit
+        let exact = lp_amount as u128 * reserve as u128;
+        prop_assert!(share as u128 * lp_supply as u128 <= exact);
+        prop_assert!((share as u128 + 1) * lp_supply as u128 > exact);
+    }
+
+    #[test]
+    fn split_redemptions_never_beat_a_single_one(
+        reserve in 0..=MAX_RESERVE,
+        lp_supply in 1..=MAX_RESERVE,
+        first_fraction in 0.0..=1.0f64,
+        second_fraction in 0.0..=1.0f64,
+    ) {
+        let first = (lp_supply as f64 * first_fraction) as u64;
+        let second = ((lp_supply - first) as f64 * second_fraction) as u64;
+
+        let first_out = math::share_of_reserve(first, reserve, lp_supply).unwrap();
+        let second_out = math::share_of_reserve(second, reserve - first_out, lp_supply - first).unwrap_or(0);
+        let single_out = math::share_of_reserve(first + second, reserve, lp_supply).unwrap();
+        prop_assert!(first_out + second_out <= single_out);
+    }
+
+    #[test]
+    fn deposit_rounding_favours_the_pool(
+        reserve_a in 1_000..=MAX_RESERVE,
+        reserve_b in 1_000..=MAX_RESERVE,
+        lp_supply in 1_000..=MAX_RESERVE,
+        amount_a in 1..=MAX_AMOUNT,
+        amount_b in 1..=MAX_AMOUNT,
+    ) {
+        // The paired amount is rounded up: it is worth at least the other side
+        let paired = math::quote_paired_amount(amount_a, reserve_a, reserve_b).unwrap();
+        prop_assert!(paired as u128 * reserve_a as u128 >= amount_a as u128 * reserve_b as u128);
+
+        // LP minted is rounded down: never more than either side is worth
+        let liquidity = math::liquidity_for_deposit(amount_a, amount_b, reserve_a, reserve_b, lp_supply).unwrap();
+        prop_assert!(liquidity as u128 * reserve_a as u128 <= amount_a as u128 * lp_supply as u128);
+        prop_assert!(liquidity as u128 * reserve_b as u128 <= amount_b as u128 * lp_supply as u128);
+    }
+
+    #[test]
+    fn deposit_then_withdraw_never_profits(
+        reserve_a in 1_000..=MAX_RESERVE,
+        reserve_b in 1_000..=MAX_RESERVE,
+        lp_supply in 1_000..=MAX_RESERVE,
+        amount_a in 1..=MAX_AMOUNT,
+        amount_b in 1..=MAX_AMOUNT,
+    ) {
+        let quote = match math::quote_add_liquidity(amount_a, amount_b, reserve_a, reserve_b, lp_supply) {
+            Ok(quote) => quote,
+            Err(err) => {
+                // Too small to mint anything: rejected, nothing is deposited
+                prop_assert_eq!(err, error!(ErrorCode::ZeroLiquidityMinted));
+                return Ok(());
+            }
+        };
+        prop_assert!(quote.amount_a <= amount_a && quote.amount_b <= amount_b);
+
+        let reserve_a = reserve_a + quote.amount_a;
+        let reserve_b = reserve_b + quote.amount_b;
+        let lp_supply = lp_supply + quote.liquidity;
+        let out_a = math::share_of_reserve(quote.liquidity, reserve_a, lp_supply).unwrap();
+        let out_b = math::share_of_reserve(quote.liquidity, reserve_b, lp_supply).unwrap();
+        prop_assert!(out_a <= quote.amount_a);
+        prop_assert!(out_b <= quote.amount_b);
+    }
+
+    #[test]
+    fn first_deposit_then_withdraw_never_profits(
+        amount_a in 1..=MAX_AMOUNT,
+        amount_b in 1..=MAX_AMOUNT,
+    ) {
+        let quote = match math::quote_add_liquidity(amount_a, amount_b, 0, 0, 0) {
+            Ok(quote) => quote,
+            Err(err) => {
+                prop_assert_eq!(err, error!(ErrorCode::InsufficientInitialLiquidity));
+                return Ok(());
+            }
+        };
+        prop_assert_eq!(quote.locked_liquidity, MINIMUM_LIQUIDITY);
+
+        // The locked liquidity stays in the supply, so the depositor can't redeem all of it
+        let lp_supply = quote.liquidity + quote.locked_liquidity;
+        let out_a = math::share_of_reserve(quote.liquidity, quote.amount_a, lp_supply).unwrap();
+        let out_b = math::share_of_reserve(quote.liquidity, quote.amount_b, lp_supply).unwrap();
+        prop_assert!(out_a < amount_a);
+        prop_assert!(out_b < amount_b);
+    }
+
+    #[test]
+    fn mul_div_rounding_differs_by_at_most_one(
+        a in any::<u32>(),
+        b in any::<u32>(),
+        denominator in 1..=u32::MAX,
+    ) {
+        let (a, b, denominator) = (a as u64, b as u64, denominator as u64);
+        let down = math::mul_div(a, b, denominator, Rounding::Down).unwrap();
+        let up = math::mul_div(a, b, denominator, Rounding::Up).unwrap();
+        let exact_multiple = (a as u128 * b as u128) % denominator as u128 == 0;
+        prop_assert_eq!(up, if exact_multiple { down } else { down + 1 });
+    }
+}
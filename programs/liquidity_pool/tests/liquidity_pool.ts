@@ -185,6 +185,7 @@ describe("liquidity_pool_program", () => {
     const amountBDesired = new BN(100 * 1e6); // 100 Token B
     const amountAMin = new BN(95 * 1e6); // Min 95 Token A
     const amountBMin = new BN(95 * 1e6); // Min 95 Token B
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 60); // Valid for one minute
 
     // Create user LP token account if it doesn't exist
     try {
@@ -206,7 +207,7 @@ describe("liquidity_pool_program", () => {
     const initialUserBReserve = new BN(initialUserBReserve_bi.toString());
 
     const tx = await program.methods
-      .addLiquidity(amountADesired, amountBDesired, amountAMin, amountBMin)
+      .addLiquidity(amountADesired, amountBDesired, amountAMin, amountBMin, deadline)
       .accounts({
         user: user.publicKey,
         pool: poolPda,
@@ -241,10 +242,13 @@ describe("liquidity_pool_program", () => {
 
     // First liquidity addition should mint sqrt(amountA * amountB) LP tokens (scaled by decimals)
     // Here, 100 * 100 = 10000, sqrt(10000) = 100. Scaled: 100 * 1e6
+    // MINIMUM_LIQUIDITY (1000) of it is locked in the dead account, the rest goes to the user
+    const minimumLiquidity = new BN(1000);
     const expectedLpMinted = new BN(100 * 1e6); // This is already BN
+    const expectedUserLp = expectedLpMinted.sub(minimumLiquidity);
     assert.ok(finalLpSupply.sub(initialLpSupply).eq(expectedLpMinted), `LP supply mismatch: ${finalLpSupply.sub(initialLpSupply)} vs ${expectedLpMinted}`);
     assert.ok(finalUserLpBalance.gt(new BN(0)), "User should receive LP tokens"); // Use BN comparison
-    assert.ok(finalUserLpBalance.eq(expectedLpMinted), `User LP balance mismatch: ${finalUserLpBalance} vs ${expectedLpMinted}`);
+    assert.ok(finalUserLpBalance.eq(expectedUserLp), `User LP balance mismatch: ${finalUserLpBalance} vs ${expectedUserLp}`);
 
 
     // Verify token movements
@@ -280,11 +284,12 @@ describe("liquidity_pool_program", () => {
     const liquidityAmountToRemove = initialUserLpBalance.div(new BN(2)); // Use BN division
     const amountAMin = new BN(45 * 1e6); // Expect ~50, set min slightly lower
     const amountBMin = new BN(45 * 1e6); // Expect ~50, set min slightly lower
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 60); // Valid for one minute
 
     assert.ok(liquidityAmountToRemove.gt(new BN(0)), "Must have LP tokens to remove");
 
     const tx = await program.methods
-      .removeLiquidity(liquidityAmountToRemove, amountAMin, amountBMin)
+      .removeLiquidity(liquidityAmountToRemove, amountAMin, amountBMin, deadline)
       .accounts({
         user: user.publicKey,
         pool: poolPda,